@@ -201,11 +201,53 @@ pub struct Project {
     pub forc_version: Option<semver::Version>,
 }
 
+#[serde_as]
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
 pub struct Network {
     #[serde(default = "default_url")]
     pub url: String,
+    /// The salt to deploy contracts to this network with, when none is provided via the CLI.
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub salt: Option<NetworkSalt>,
+}
+
+/// The salt used when deploying contracts to a [Network].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum NetworkSalt {
+    /// The default salt (`0x00..00`), for reproducible contract IDs.
+    Default,
+    /// A new random salt for every deployment.
+    Random,
+    /// An explicit salt.
+    Explicit(HexSalt),
+}
+
+impl FromStr for NetworkSalt {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "default" => Ok(Self::Default),
+            "random" => Ok(Self::Random),
+            _ => HexSalt::from_str(s).map(Self::Explicit).map_err(|e| {
+                anyhow!(
+                    "invalid network salt `{s}`, expected `default`, `random` or a hex salt: {e}"
+                )
+            }),
+        }
+    }
+}
+
+impl Display for NetworkSalt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Default => write!(f, "default"),
+            Self::Random => write!(f, "random"),
+            Self::Explicit(salt) => write!(f, "0x{salt}"),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -1060,6 +1102,28 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn deserialize_network_salt() {
+        let network: Network = toml::from_str(r#"url = "http://127.0.0.1:4000""#).unwrap();
+        assert_eq!(network.salt, None);
+
+        let network: Network = toml::from_str(r#"salt = "default""#).unwrap();
+        assert_eq!(network.salt, Some(NetworkSalt::Default));
+
+        let network: Network = toml::from_str(r#"salt = "random""#).unwrap();
+        assert_eq!(network.salt, Some(NetworkSalt::Random));
+
+        let salt = "0x1111111111111111111111111111111111111111111111111111111111111111";
+        let network: Network = toml::from_str(&format!(r#"salt = "{salt}""#)).unwrap();
+        assert_eq!(
+            network.salt,
+            Some(NetworkSalt::Explicit(HexSalt::from_str(salt).unwrap()))
+        );
+        assert_eq!(network.salt.unwrap().to_string(), salt);
+
+        assert!(toml::from_str::<Network>(r#"salt = "sometimes""#).is_err());
+    }
+
     #[test]
     fn deserialize_contract_dependency() {
         let contract_dep_str = r#"{"path": "../", "salt": "0x1111111111111111111111111111111111111111111111111111111111111111" }"#;
@@ -1,12 +1,48 @@
 use clap::Parser;
-use forc_tracing::{init_tracing_subscriber, println_error};
+use forc_client::op::{CancellationToken, DeployCancelled, DeployOptions};
+use forc_tracing::{
+    init_tracing_subscriber, println_error, println_warning, TracingSubscriberOptions,
+    TracingWriterMode,
+};
 
 #[tokio::main]
 async fn main() {
-    init_tracing_subscriber(Default::default());
     let command = forc_client::cmd::Deploy::parse();
-    if let Err(err) = forc_client::op::deploy(command).await {
+    // Keep stdout free for the JSON output.
+    let writer_mode = command.json.then_some(TracingWriterMode::Stderr);
+    init_tracing_subscriber(TracingSubscriberOptions {
+        writer_mode,
+        ..Default::default()
+    });
+    let options = DeployOptions::default().with_cancellation(cancel_on_ctrl_c());
+    if let Err(err) = forc_client::op::deploy_with_options(command, options).await {
         println_error(&format!("{}", err));
-        std::process::exit(1);
+        let exit_code = if err.is::<DeployCancelled>() {
+            DeployCancelled::EXIT_CODE
+        } else {
+            1
+        };
+        std::process::exit(exit_code);
     }
 }
+
+/// Returns a token cancelled when the user presses Ctrl-C, so that the deployment stops before
+/// submitting its next transaction. Pressing Ctrl-C again exits right away, eg. while a prompt
+/// waits for input.
+fn cancel_on_ctrl_c() -> CancellationToken {
+    let cancellation = CancellationToken::default();
+    tokio::spawn({
+        let cancellation = cancellation.clone();
+        async move {
+            if tokio::signal::ctrl_c().await.is_err() {
+                return;
+            }
+            println_warning("Cancelling the deployment, press Ctrl-C again to exit right away");
+            cancellation.cancel();
+            if tokio::signal::ctrl_c().await.is_ok() {
+                std::process::exit(DeployCancelled::EXIT_CODE);
+            }
+        }
+    });
+    cancellation
+}
@@ -1,10 +1,19 @@
-use crate::NodeTarget;
+use crate::{
+    constants::{
+        DEFAULT_DEPLOYMENTS_DIR, DEFAULT_NODE_TIMEOUT_MS, DEFAULT_POLL_INTERVAL_MS,
+        DEFAULT_RETRY_BACKOFF_MS, TX_SUBMIT_TIMEOUT_MS,
+    },
+    NodeTarget,
+};
 use clap::Parser;
+use devault::Devault;
 pub use forc::cli::shared::{BuildOutput, Minify, Pkg, Print};
 use forc_pkg::BuildProfile;
 pub use forc_tx::{Gas, Maturity};
 pub use forc_util::tx_utils::Salt;
 use fuel_crypto::SecretKey;
+use fuel_tx::{Bytes32, ContractId};
+use std::path::PathBuf;
 
 forc_util::cli_examples! {
    super::Command {
@@ -14,7 +23,7 @@ forc_util::cli_examples! {
     }
 }
 
-#[derive(Debug, Default, Parser)]
+#[derive(Debug, Devault, Parser)]
 #[clap(bin_name = "forc deploy", version, after_help = help())]
 pub struct Command {
     #[clap(flatten)]
@@ -25,6 +34,18 @@ pub struct Command {
     pub print: Print,
     #[clap(flatten)]
     pub gas: Gas,
+    /// Fetch the tip paid by each deployment transaction from a gas oracle, unless
+    /// `--gas-price` is set.
+    ///
+    /// The oracle must respond to a GET request with a JSON object holding the recommended tip
+    /// in its `tip` field. It is queried once per deployment, and the transactions are deployed
+    /// without a tip if it can't be reached.
+    #[clap(long, value_name = "URL")]
+    pub gas_oracle: Option<String>,
+    /// Scale the tip given by `--gas-price` or recommended by the `--gas-oracle` by the given
+    /// factor, eg. `1.5` to deploy more aggressively. Applies to every deployment transaction.
+    #[clap(long, value_name = "FLOAT")]
+    pub tip_multiplier: Option<f64>,
     #[clap(flatten)]
     pub maturity: Maturity,
     #[clap(flatten)]
@@ -44,11 +65,59 @@ pub struct Command {
     /// Useful for CI, to create reproducible deployments.
     #[clap(long)]
     pub default_salt: bool,
+    /// Read the salt of each contract from a TOML file mapping contract names to salts, as
+    /// written by `--write-salts`.
+    ///
+    /// Contracts missing from the file are deployed with the default salt.
+    #[clap(long, conflicts_with_all = ["salt", "default_salt"])]
+    pub salt_file: Option<PathBuf>,
+    /// Derive the salt of each contract from the commit checked out in the git repository of the
+    /// package, and the contract's name.
+    ///
+    /// This ties the deployed contract IDs to the source revision they were deployed from.
+    /// Refuses to deploy from a working tree with uncommitted changes unless `--allow-dirty` is
+    /// passed.
+    #[clap(long, conflicts_with_all = ["salt", "default_salt", "salt_file"])]
+    pub salt_from_git: bool,
+    /// Allow `--salt-from-git` to derive salts while the working tree has uncommitted changes.
+    #[clap(long, requires = "salt_from_git")]
+    pub allow_dirty: bool,
+    /// Write the salt used for each deployed contract to a TOML file, which can be passed back
+    /// via `--salt-file` to reproduce the same contract IDs.
+    #[clap(long)]
+    pub write_salts: Option<PathBuf>,
+    /// A 256-bit hexadecimal prefix mixed into the salt of every contract by hashing, to
+    /// namespace the resulting contract IDs.
+    ///
+    /// The prefix is applied on top of the salt selected by `--salt`, `--salt-file`,
+    /// `--default-salt` or the manifest, so the same prefix and salt always yield the same
+    /// contract ID. `--write-salts` records the salts before the prefix is applied.
+    #[clap(long, value_name = "HEX")]
+    pub salt_prefix: Option<Bytes32>,
+    /// Equivalent to passing both `--locked` and `--offline`, for hermetic deployments.
+    ///
+    /// Builds exactly the dependency graph pinned in `Forc.lock` without network access, and
+    /// exits with an error if the lock file is missing or needs to be updated.
+    #[clap(long)]
+    pub frozen: bool,
     #[clap(flatten)]
     pub build_output: BuildOutput,
+    /// The directory deployment artifacts are written to, relative to the output directory.
+    ///
+    /// Must stay within the output directory, eg.: `--deployments-dir forc/deployments`.
+    #[clap(long, value_name = "REL_PATH", default_value = DEFAULT_DEPLOYMENTS_DIR)]
+    #[devault("DEFAULT_DEPLOYMENTS_DIR.to_string()")]
+    pub deployments_dir: String,
     /// The name of the build profile to use.
     #[clap(long, default_value = BuildProfile::RELEASE)]
+    #[devault("BuildProfile::RELEASE.to_string()")]
     pub build_profile: String,
+    /// Allow deploying a build that doesn't use the release profile to a non-local node.
+    ///
+    /// Such builds are unoptimized, so deploying them anywhere but to a local node is refused by
+    /// default.
+    #[clap(long)]
+    pub allow_debug_deploy: bool,
     /// Sign the transaction with default signer that is pre-funded by fuel-core. Useful for testing against local node.
     #[clap(long)]
     pub default_signer: bool,
@@ -57,9 +126,27 @@ pub struct Command {
     pub unsigned: bool,
     /// Set the key to be used for signing.
     pub signing_key: Option<SecretKey>,
+    /// Sign with the forc-wallet key at the given HD derivation path, instead of choosing one of
+    /// the wallet's accounts, eg.: `--hd-path "m/44'/1179993420'/0'/0/0"`.
+    #[clap(long, value_name = "PATH")]
+    pub hd_path: Option<String>,
     /// Sign the deployment transaction manually.
     #[clap(long)]
     pub manual_signing: bool,
+    /// Set the key of an account that pays the deployment fees on behalf of the deployer.
+    ///
+    /// The deployer still signs the deployment transaction, but the fee is covered by this
+    /// account's coins, so the deployer does not need to hold any of the base asset.
+    #[clap(long)]
+    pub fee_payer_key: Option<SecretKey>,
+    /// Pay the deployment fees with the base asset coin with the given UTXO ID, rather than
+    /// with coins selected automatically. Can be passed multiple times.
+    ///
+    /// The coins must belong to the account paying the fees and together cover the estimated
+    /// fee. When deploying multiple contracts, each transaction spends the given coins that
+    /// earlier transactions didn't.
+    #[clap(long = "input-coin", value_name = "UTXO_ID")]
+    pub input_coins: Vec<String>,
     /// Override storage slot initialization.
     ///
     /// By default, storage slots are initialized with the values defined in the storage block in
@@ -67,7 +154,7 @@ pub struct Command {
     /// containing the overridden values.
     ///
     /// The file format and key values should match the compiler-generated `*-storage_slots.json` file in the output
-    /// directory of the compiled contract.
+    /// directory of the compiled contract. Each key may only be initialized once.
     ///
     /// Example: `forc deploy --override-storage-slots my_override.json`
     ///
@@ -78,10 +165,345 @@ pub struct Command {
     ///     "value": "0000000000000000000000000000000000000000000000000000000000000001"
     ///   }
     /// ]
+    ///
+    /// May be passed multiple times to deploy one instance of each contract per file, each with
+    /// its own contract ID and deployment artifact. Several files can't be combined with
+    /// `--write-salts`, `--post-deploy-cmd` or `--if-abi-changed`, which handle one deployment
+    /// per contract.
     #[clap(long, verbatim_doc_comment, name = "JSON_FILE_PATH")]
-    pub override_storage_slots: Option<String>,
+    pub override_storage_slots: Vec<String>,
+
+    /// Print the storage slots of the contract(s) as JSON and exit, without connecting to a node.
+    ///
+    /// The output of a single contract can be used as a starting point for an
+    /// `--override-storage-slots` file. The storage slots of a workspace are keyed by contract
+    /// name.
+    #[clap(long)]
+    pub print_storage_slots: bool,
+    /// Print the IDs the contract(s) would be deployed with and exit, without connecting to a
+    /// node.
+    ///
+    /// The salt must be given via `--salt`, `--salt-file`, `--salt-from-git`, `--default-salt`
+    /// or the manifest, since a random salt yields an unpredictable ID. Configurable and storage
+    /// slot overrides are taken into account.
+    #[clap(long, conflicts_with = "spawn_node")]
+    pub predict_id: bool,
+    /// Compare the contract(s) against the network and their latest deployment artifacts, print
+    /// which would be new, unchanged or redeployed, and exit without deploying.
+    ///
+    /// With `--json`, the changes are printed as JSON keyed by contract name.
+    #[clap(long, conflicts_with = "spawn_node")]
+    pub diff: bool,
+
+    /// Override the value of a configurable constant of the contract, eg.:
+    /// `forc deploy --configurable MAX_SUPPLY=100`.
+    ///
+    /// May be passed multiple times. When deploying a workspace, the value is applied to every
+    /// contract declaring a configurable with that name. Requires the new encoding.
+    #[clap(long = "configurable", value_name = "NAME=VALUE")]
+    pub configurables: Vec<String>,
+    /// Refuse to deploy contracts with integer configurables that are zero, as they were likely
+    /// left unset. Such configurables are only warned about by default.
+    #[clap(long)]
+    pub strict_configurables: bool,
 
     /// Disable the "new encoding" feature
     #[clap(long)]
     pub no_encoding_v1: bool,
+
+    /// Spawn an ephemeral in-memory `fuel-core` node, deploy to it and shut it down afterwards.
+    ///
+    /// Requires `fuel-core` to be installed. Implies `--default-signer`, as the spawned node
+    /// funds the default signer's account.
+    #[clap(long)]
+    pub spawn_node: bool,
+
+    /// Print additional details about each deployment, including the salt, bytecode root and
+    /// storage state root from which the contract ID is derived.
+    #[clap(long)]
+    pub verbose: bool,
+
+    /// The interval, in milliseconds, at which the node is polled while waiting for a
+    /// deployment transaction to be committed.
+    ///
+    /// Increase this against public nodes that rate-limit requests.
+    #[clap(long, default_value_t = DEFAULT_POLL_INTERVAL_MS)]
+    #[devault("DEFAULT_POLL_INTERVAL_MS")]
+    pub poll_interval_ms: u64,
+
+    /// The maximum time, in milliseconds, to wait for the node when connecting to it and
+    /// querying its chain info, so that an unreachable node fails fast.
+    ///
+    /// This is separate from the time waited for a deployment to be committed.
+    #[clap(long, default_value_t = DEFAULT_NODE_TIMEOUT_MS)]
+    #[devault("DEFAULT_NODE_TIMEOUT_MS")]
+    pub node_timeout_ms: u64,
+
+    /// The maximum time, in milliseconds, to wait for a submitted deployment to be committed.
+    ///
+    /// Extended, with a warning, to three block intervals of networks whose recent blocks were
+    /// produced too slowly for the timeout to cover them.
+    #[clap(long, default_value_t = TX_SUBMIT_TIMEOUT_MS)]
+    #[devault("TX_SUBMIT_TIMEOUT_MS")]
+    pub submit_timeout_ms: u64,
+
+    /// The number of times a deployment submission is retried after failing to reach the node.
+    ///
+    /// Submissions rejected by the node aren't retried, and a submission is not sent again if the
+    /// node already received it.
+    #[clap(long, default_value_t = 0)]
+    pub retries: u32,
+
+    /// The delay, in milliseconds, before retrying a failed submission, doubled for each
+    /// following retry.
+    #[clap(long, default_value_t = DEFAULT_RETRY_BACKOFF_MS)]
+    #[devault("DEFAULT_RETRY_BACKOFF_MS")]
+    pub retry_backoff_ms: u64,
+
+    /// Encrypt the deployment artifacts with a password, prompted for before deploying.
+    ///
+    /// Encrypted artifacts are written with the `.json.enc` extension instead of `.json`.
+    #[clap(long)]
+    pub encrypt_artifact: bool,
+
+    /// Compare the ABI of the contract(s) against the one of an already deployed contract,
+    /// reporting added, removed and changed functions, without deploying anything.
+    ///
+    /// ABIs are not published on-chain, so the ABI of the given contract is read from its
+    /// deployment artifact, which must be in the deployments directory.
+    #[clap(long, value_name = "CONTRACT_ID")]
+    pub abi_compat_check: Option<ContractId>,
+
+    /// Print results as JSON instead of human readable output.
+    ///
+    /// Each deployed contract is printed to stdout as a JSON object on its own line, while
+    /// progress output is written to stderr.
+    #[clap(long)]
+    pub json: bool,
+    /// Print the path of the JSON ABI the build wrote for each deployed contract, eg. for SDK
+    /// code generation.
+    ///
+    /// With `--json`, the path is included as `abi_path`.
+    #[clap(long)]
+    pub print_abi_path: bool,
+    /// Print how the bytecode of each deployed contract splits into code, data and
+    /// configurables, eg. to find what to reduce when close to the contract size limit.
+    ///
+    /// With `--json`, the sizes in bytes are included as `size_breakdown`.
+    #[clap(long)]
+    pub size_breakdown: bool,
+    /// Write a bundle of each deployed contract to the given directory, as
+    /// `<CONTRACT_NAME>-<CONTRACT_ID>.tar.gz`.
+    ///
+    /// A bundle contains the deployed bytecode, the JSON ABI, the storage slots and the
+    /// deployment artifact, along with a `manifest.json` listing the SHA-256 hash of each file.
+    /// Bundles of the same deployment are byte for byte identical.
+    ///
+    /// Bundles store the deployment artifact as plain text, so they can't be written along with
+    /// `--encrypt-artifact`.
+    #[clap(long, value_name = "DIR", conflicts_with = "encrypt_artifact")]
+    pub bundle: Option<PathBuf>,
+
+    /// Snapshot the node's consensus parameters to the given file on the first deployment, and
+    /// warn on subsequent deployments if the node's parameters no longer match the snapshot.
+    ///
+    /// Useful for deployment campaigns that must be reproducible.
+    #[clap(long, value_name = "PATH")]
+    pub consensus_params_snapshot: Option<PathBuf>,
+
+    /// Keep deploying the remaining contracts of a workspace when one of them fails to deploy.
+    ///
+    /// The failed contracts are reported once all others were deployed.
+    #[clap(long)]
+    pub keep_going: bool,
+
+    /// Diagnose common environment issues without deploying anything.
+    ///
+    /// Checks that a wallet or signing key is available, that the node is reachable, that the
+    /// signer's accounts are funded, and that each contract fits within the node's contract size
+    /// limit.
+    #[clap(long, conflicts_with = "spawn_node")]
+    pub doctor: bool,
+
+    /// A shell command to run once the contracts are built, before the first deployment is
+    /// submitted.
+    ///
+    /// The deployment is aborted if the command fails.
+    #[clap(long, value_name = "CMD")]
+    pub pre_deploy_cmd: Option<String>,
+
+    /// A shell command to run once all contracts were deployed.
+    ///
+    /// The command receives the node URL as `FORC_DEPLOY_NODE_URL`, the names of the deployed
+    /// contracts as `FORC_DEPLOY_CONTRACTS`, and the ID of each contract as
+    /// `FORC_DEPLOY_CONTRACT_ID_<NAME>`, with the name in uppercase and dashes replaced by
+    /// underscores. A failure of the command is reported, but doesn't affect the deployed
+    /// contracts.
+    #[clap(long, value_name = "CMD")]
+    pub post_deploy_cmd: Option<String>,
+
+    /// Deploy the package at the root of the given git repository rather than a local one.
+    ///
+    /// The repository is fetched to forc's git checkouts and built in a temporary workspace, with
+    /// the build and deployment artifacts written to the output directory of the current one.
+    /// With `--offline`, only a previously fetched `--rev` can be deployed.
+    #[clap(long, value_name = "URL")]
+    pub git: Option<String>,
+    /// The branch of the `--git` repository to deploy.
+    #[clap(long, requires = "git", conflicts_with_all = ["git_tag", "rev"])]
+    pub branch: Option<String>,
+    /// The tag of the `--git` repository to deploy.
+    #[clap(long, requires = "git", conflicts_with = "rev")]
+    pub git_tag: Option<String>,
+    /// The commit of the `--git` repository to deploy.
+    #[clap(long, requires = "git")]
+    pub rev: Option<String>,
+
+    /// Rehearse the deployment against the node at the given URL, running a fork or snapshot of
+    /// the network's state.
+    ///
+    /// The run is labeled as a rehearsal, and its deployment artifacts, which record the fork and
+    /// its block height, are written to the `rehearsals` directory of the output directory rather
+    /// than the deployments directory.
+    #[clap(long, value_name = "URL", conflicts_with = "spawn_node")]
+    pub fork: Option<String>,
+
+    /// Tag the deployment with a piece of metadata, eg. `--tag ticket=OPS-42`.
+    ///
+    /// May be passed multiple times. Tags are recorded in the deployment artifacts and included
+    /// in the `--json` output.
+    #[clap(long = "tag", value_name = "KEY=VALUE")]
+    pub tags: Vec<String>,
+
+    /// Resume an interrupted deployment, skipping the contracts that were already deployed.
+    ///
+    /// A contract is skipped if its latest deployment artifact for the network records the
+    /// contract ID it would be deployed with, and that contract is found on-chain. This requires
+    /// the same salts as the interrupted run, eg. via `--salt-file`, `--salt-from-git` or
+    /// `--default-salt`.
+    #[clap(long)]
+    pub resume: bool,
+
+    /// Require typing the name of each non-local network before deploying to it, as a stronger
+    /// safeguard than a yes/no prompt for production deployments.
+    ///
+    /// The name is the chain name reported by the node, eg. `Fuel Sepolia Testnet`. Requires an
+    /// interactive terminal.
+    #[clap(long)]
+    pub confirm_network: bool,
+    /// Answer yes to the confirmation prompts, for unattended deployments.
+    ///
+    /// Confirms signing each deployment transaction with the selected forc-wallet account, and
+    /// creating a new wallet if there is none at the default path. The account to sign with is
+    /// still prompted for unless `--hd-path` is set, and the wallet password unless signing with
+    /// `--default-signer` or `--signing-key`.
+    #[clap(long, short = 'y', conflicts_with = "confirm_network")]
+    pub yes: bool,
+
+    /// Capture the receipts of each deployment transaction in the deployed contracts returned to
+    /// library users.
+    #[clap(long)]
+    pub receipts: bool,
+
+    /// A friendly name for the network deployed to, eg. `mainnet`, shown in the output and
+    /// recorded in the deployment artifacts alongside the node URL.
+    ///
+    /// Defaults to the name of the well-known network at the node URL, if any.
+    #[clap(long, value_name = "NAME")]
+    pub network_name: Option<String>,
+
+    /// Show the estimated fee of each deployment as a decimal number with the given number of
+    /// decimals, eg. `9` for the base asset's coin rather than its base units.
+    ///
+    /// The estimate is also included in base units in the `--json` output.
+    #[clap(long, value_name = "DECIMALS")]
+    pub fee_decimals: Option<u8>,
+    /// Also show the approximate fiat cost of each deployment, given the price of one coin of the
+    /// base asset as denominated by `--fee-decimals`.
+    #[clap(long, value_name = "PRICE", requires = "fee_decimals")]
+    pub fee_price: Option<f64>,
+
+    /// Fail if a contract with the ID a contract would be deployed with already exists on-chain,
+    /// rather than submitting a deployment that can't succeed.
+    ///
+    /// An existing contract means the salt and bytecode were deployed before, so a different salt
+    /// is needed for a fresh deployment.
+    #[clap(long)]
+    pub fail_on_existing: bool,
+    /// If a contract with the ID a contract would be deployed with already exists on-chain,
+    /// increment the salt by one until the ID is free, eg. to redeploy with mostly stable IDs
+    /// during development.
+    ///
+    /// The salt actually used is printed and recorded in the deployment artifact. Gives up after
+    /// 100 attempts. As the bumped salts aren't passed back, it can't be combined with `--resume`.
+    #[clap(long, conflicts_with_all = ["fail_on_existing", "write_salts", "resume"])]
+    pub salt_auto_bump: bool,
+
+    /// Record the storage slots each contract is deployed with in its deployment artifact, so that
+    /// its initial state and state root can be audited from the artifact alone.
+    ///
+    /// Off by default, as contracts with many storage slots make for large artifacts.
+    #[clap(long)]
+    pub record_storage_slots: bool,
+
+    /// Sign each deployment artifact with the key that signed the deployment, writing the
+    /// detached signature next to the artifact as `<ARTIFACT>.sig`.
+    ///
+    /// The signature covers the canonical JSON of the artifact, so that consumers receiving the
+    /// artifact out-of-band can check that it wasn't altered. Encrypted artifacts can't be
+    /// verified against it, so it can't be combined with `--encrypt-artifact`.
+    #[clap(long, conflicts_with = "encrypt_artifact")]
+    pub sign_artifact: bool,
+
+    /// Skip checking that each node runs the version of fuel-core forc-client is built against.
+    ///
+    /// A mismatch is only warned about, as it may explain otherwise unexpected submission
+    /// failures.
+    #[clap(long)]
+    pub skip_node_version_check: bool,
+
+    /// Also build the predicates of the workspace, printing the address of each and recording it
+    /// in a `<PREDICATE_NAME>-predicate-<ADDRESS>.json` artifact next to the deployment artifacts.
+    ///
+    /// Predicates aren't deployed: their address is the root of their bytecode, and can be funded
+    /// as soon as it is known.
+    #[clap(long)]
+    pub include_predicates: bool,
+
+    /// Set the given `Address` configurable of the contract(s) to the address of the deployer,
+    /// eg. to make the deployer the initial owner without a separate initialization call.
+    ///
+    /// As this changes the bytecode, the contract ID depends on the deployer. Requires the new
+    /// encoding.
+    #[clap(long, value_name = "NAME", conflicts_with_all = ["predict_id", "diff", "resume"])]
+    pub set_deployer_configurable: Option<String>,
+
+    /// Only deploy the contracts whose ABI differs from the one recorded in their latest
+    /// deployment artifact for the network, printing the differences.
+    ///
+    /// Contracts with an unchanged ABI are skipped, and the contract recorded in the artifact is
+    /// returned in their place. Contracts without a recorded ABI are deployed.
+    #[clap(long)]
+    pub if_abi_changed: bool,
+
+    /// Print the JSON Schema of the deployment artifacts and exit, without building or
+    /// connecting to a node.
+    ///
+    /// The schema is versioned along with the `schema_version` recorded in each artifact.
+    #[clap(long)]
+    pub print_json_schema: bool,
+
+    /// List the deployments recorded in the deployment artifacts of the contract(s) and exit,
+    /// without connecting to a node.
+    ///
+    /// Encrypted artifacts are not listed, and malformed ones are skipped with a warning. With
+    /// `--json`, the artifacts are printed as a JSON array.
+    #[clap(long, conflicts_with = "spawn_node")]
+    pub list_deployments: bool,
+    /// Only list the deployments to the network with the given name or node URL.
+    #[clap(long, value_name = "NAME_OR_URL", requires = "list_deployments")]
+    pub list_network: Option<String>,
+    /// Only list the deployments of the contract with the given name.
+    #[clap(long, value_name = "NAME", requires = "list_deployments")]
+    pub list_contract: Option<String>,
 }
@@ -17,3 +17,31 @@ pub const DEFAULT_PRIVATE_KEY: &str =
     "0xde97d8624a438121b86a1956544bd72ed68cd69f2c99555b08b1e8c51ffd511c";
 /// The maximum time to wait for a transaction to be included in a block by the node
 pub const TX_SUBMIT_TIMEOUT_MS: u64 = 30_000u64;
+/// The maximum time to wait for a spawned local node to become healthy
+pub const LOCAL_NODE_STARTUP_TIMEOUT_MS: u64 = 10_000u64;
+/// The interval at which a spawned local node is polled for its health
+pub const LOCAL_NODE_STARTUP_POLL_MS: u64 = 100u64;
+/// The default interval at which the node is polled for a submitted transaction's status
+pub const DEFAULT_POLL_INTERVAL_MS: u64 = 500u64;
+/// The default directory, relative to the output directory, deployment artifacts are written to
+pub const DEFAULT_DEPLOYMENTS_DIR: &str = "deployments";
+/// The directory, relative to the output directory, the artifacts of `--fork` rehearsals are written to
+pub const REHEARSALS_DIR: &str = "rehearsals";
+/// The default maximum time to wait for the node when connecting to it and querying its chain info
+pub const DEFAULT_NODE_TIMEOUT_MS: u64 = 10_000u64;
+/// The default delay before retrying a failed deployment submission
+pub const DEFAULT_RETRY_BACKOFF_MS: u64 = 1_000u64;
+/// The version of fuel-core whose node API forc-client is built against, kept in sync with the
+/// `fuel-core-client` dependency
+pub const SUPPORTED_FUEL_CORE_VERSION: &str = "0.31.0";
+/// The maximum number of times `--salt-auto-bump` increments the salt of a contract
+pub const MAX_SALT_BUMPS: u32 = 100;
+/// The number of recent blocks the block production interval of a node is averaged over
+pub const BLOCK_INTERVAL_SAMPLE_BLOCKS: u32 = 10;
+/// The minimum number of block intervals to wait for a submitted transaction to be committed
+pub const MIN_SUBMIT_TIMEOUT_BLOCKS: u32 = 3;
+/// The number of blocks ahead the gas price is estimated for
+pub const GAS_PRICE_BLOCK_HORIZON: u32 = 10;
+/// The version of the deployment artifact format, recorded in each artifact and in its JSON
+/// schema, bumped whenever the fields of the artifact change
+pub const DEPLOYMENT_ARTIFACT_SCHEMA_VERSION: u32 = 1;
@@ -0,0 +1,173 @@
+use anyhow::Result;
+use fuel_abi_types::abi::{
+    full_program::{FullProgramABI, FullTypeApplication},
+    program::ProgramABI,
+};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use tracing::info;
+
+/// The differences between the functions of two contract ABIs.
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+pub(crate) struct AbiDiff {
+    /// Signatures of the functions only present in the new ABI.
+    added: Vec<String>,
+    /// Signatures of the functions only present in the old ABI.
+    removed: Vec<String>,
+    /// Functions present in both ABIs with different signatures.
+    changed: Vec<ChangedFunction>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+struct ChangedFunction {
+    name: String,
+    old: String,
+    new: String,
+}
+
+impl AbiDiff {
+    /// Compares the functions of the `old` ABI against those of the `new` one.
+    pub(crate) fn between(old: &ProgramABI, new: &ProgramABI) -> Result<Self> {
+        let old = function_signatures(old)?;
+        let mut new = function_signatures(new)?;
+        let mut diff = Self::default();
+        for (name, old_signature) in old {
+            match new.remove(&name) {
+                Some(new_signature) if new_signature != old_signature => {
+                    diff.changed.push(ChangedFunction {
+                        name,
+                        old: old_signature,
+                        new: new_signature,
+                    })
+                }
+                Some(_) => {}
+                None => diff.removed.push(old_signature),
+            }
+        }
+        diff.added = new.into_values().collect();
+        Ok(diff)
+    }
+
+    /// Whether callers of the old ABI can call the new one, ie. no function was removed or changed.
+    pub(crate) fn is_compatible(&self) -> bool {
+        self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Prints a human readable summary of the differences.
+    pub(crate) fn print_summary(&self) {
+        if self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty() {
+            info!("  No ABI changes");
+            return;
+        }
+        for signature in &self.added {
+            info!("  + {signature}");
+        }
+        for signature in &self.removed {
+            info!("  - {signature}");
+        }
+        for function in &self.changed {
+            info!("  ~ {}\n      was: {}", function.new, function.old);
+        }
+        info!(
+            "  {} added, {} removed, {} changed",
+            self.added.len(),
+            self.removed.len(),
+            self.changed.len()
+        );
+    }
+}
+
+/// Returns the signature of each function of the given ABI, keyed by function name.
+fn function_signatures(abi: &ProgramABI) -> Result<BTreeMap<String, String>> {
+    let abi = FullProgramABI::from_counterpart(abi)?;
+    Ok(abi
+        .functions
+        .iter()
+        .map(|function| {
+            let inputs = function
+                .inputs()
+                .iter()
+                .map(|input| format!("{}: {}", input.name, type_name(input)))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let signature = format!(
+                "fn {}({inputs}) -> {}",
+                function.name(),
+                type_name(function.output())
+            );
+            (function.name().to_string(), signature)
+        })
+        .collect())
+}
+
+/// Returns the name of the given type, including its type arguments.
+fn type_name(ty: &FullTypeApplication) -> String {
+    if ty.type_arguments.is_empty() {
+        ty.type_decl.type_field.clone()
+    } else {
+        let type_arguments = ty
+            .type_arguments
+            .iter()
+            .map(type_name)
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("{}<{type_arguments}>", ty.type_decl.type_field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn abi(functions: &str) -> ProgramABI {
+        let json_abi = format!(
+            r#"{{"types":[{{"typeId":0,"type":"()","components":[],"typeParameters":null}},
+{{"typeId":1,"type":"bool","components":null,"typeParameters":null}},{{"typeId":2,"type":"u64",
+"components":null,"typeParameters":null}}],"functions":[{functions}],"loggedTypes":[],
+"messagesTypes":[],"configurables":[]}}"#
+        );
+        serde_json::from_str(&json_abi).unwrap()
+    }
+
+    fn function(name: &str, input_type: usize, output_type: usize) -> String {
+        format!(
+            r#"{{"inputs":[{{"name":"arg","type":{input_type},"typeArguments":null}}],"name":"{name}",
+"output":{{"name":"","type":{output_type},"typeArguments":null}},"attributes":null}}"#
+        )
+    }
+
+    #[test]
+    fn test_abi_diff() {
+        let old = abi(&[
+            function("kept", 1, 0),
+            function("changed", 1, 0),
+            function("removed", 2, 0),
+        ]
+        .join(","));
+        let new = abi(&[
+            function("kept", 1, 0),
+            function("changed", 2, 0),
+            function("added", 2, 1),
+        ]
+        .join(","));
+
+        let diff = AbiDiff::between(&old, &new).unwrap();
+        assert_eq!(
+            diff,
+            AbiDiff {
+                added: vec!["fn added(arg: u64) -> bool".to_string()],
+                removed: vec!["fn removed(arg: u64) -> ()".to_string()],
+                changed: vec![ChangedFunction {
+                    name: "changed".to_string(),
+                    old: "fn changed(arg: bool) -> ()".to_string(),
+                    new: "fn changed(arg: u64) -> ()".to_string(),
+                }],
+            }
+        );
+        assert!(!diff.is_compatible());
+
+        let diff = AbiDiff::between(&old, &old).unwrap();
+        assert_eq!(diff, AbiDiff::default());
+        assert!(diff.is_compatible());
+    }
+}
@@ -0,0 +1,144 @@
+use super::DeploymentArtifact;
+use anyhow::Result;
+use flate2::{write::GzEncoder, Compression};
+use fuel_crypto::Hasher;
+use fuel_tx::{ContractId, StorageSlot};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use sway_core::asm_generation::ProgramABI;
+
+/// Describes the files of a deployment bundle, stored in the bundle as `manifest.json`.
+#[derive(Debug, Serialize)]
+struct BundleManifest<'a> {
+    contract: &'a str,
+    contract_id: String,
+    network_endpoint: &'a str,
+    files: Vec<BundleFile>,
+}
+
+#[derive(Debug, Serialize)]
+struct BundleFile {
+    path: String,
+    sha256: String,
+}
+
+/// The contents of a deployed contract that make up its deployment bundle.
+pub(crate) struct Bundle<'a> {
+    pub(crate) pkg_name: &'a str,
+    pub(crate) contract_id: ContractId,
+    pub(crate) bytecode: &'a [u8],
+    pub(crate) program_abi: &'a ProgramABI,
+    pub(crate) storage_slots: &'a [StorageSlot],
+    pub(crate) artifact: &'a DeploymentArtifact,
+}
+
+impl Bundle<'_> {
+    /// Writes the bundle to `<bundle_dir>/<pkg_name>-0x<contract_id>.tar.gz`, returning its path.
+    ///
+    /// Entries are written in a fixed order with fixed metadata, so the same deployment always
+    /// yields the same archive.
+    pub(crate) fn write(&self, bundle_dir: &Path) -> Result<PathBuf> {
+        std::fs::create_dir_all(bundle_dir)?;
+        let path = bundle_dir.join(format!("{}-0x{}.tar.gz", self.pkg_name, self.contract_id));
+        let file = std::fs::File::create(&path)?;
+        let mut archive = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+        for (name, contents) in self.entries()? {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o644);
+            header.set_mtime(0);
+            header.set_uid(0);
+            header.set_gid(0);
+            header.set_cksum();
+            archive.append_data(&mut header, name, contents.as_slice())?;
+        }
+        archive.into_inner()?.finish()?;
+        Ok(path)
+    }
+
+    /// Returns the name and contents of each file of the bundle, ending with the manifest.
+    fn entries(&self) -> Result<Vec<(String, Vec<u8>)>> {
+        let pkg_name = self.pkg_name;
+        let mut entries = vec![(format!("{pkg_name}.bin"), self.bytecode.to_vec())];
+        if let ProgramABI::Fuel(abi) = self.program_abi {
+            entries.push((
+                format!("{pkg_name}-abi.json"),
+                serde_json::to_vec_pretty(abi)?,
+            ));
+        }
+        entries.push((
+            format!("{pkg_name}-storage_slots.json"),
+            serde_json::to_vec_pretty(self.storage_slots)?,
+        ));
+        entries.push((
+            format!("{pkg_name}-deployment-0x{}.json", self.contract_id),
+            serde_json::to_vec_pretty(self.artifact)?,
+        ));
+
+        let manifest = BundleManifest {
+            contract: pkg_name,
+            contract_id: format!("0x{}", self.contract_id),
+            network_endpoint: &self.artifact.network_endpoint,
+            files: entries
+                .iter()
+                .map(|(path, contents)| BundleFile {
+                    path: path.clone(),
+                    sha256: format!("0x{}", Hasher::hash(contents)),
+                })
+                .collect(),
+        };
+        entries.push((
+            "manifest.json".to_string(),
+            serde_json::to_vec_pretty(&manifest)?,
+        ));
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::read::GzDecoder;
+
+    #[test]
+    fn test_bundle_is_deterministic() {
+        let dir = tempfile::tempdir().unwrap();
+        let artifact = super::super::test::test_deployment_artifact();
+        let storage_slots = vec![StorageSlot::default()];
+        let bundle = Bundle {
+            pkg_name: "contract",
+            contract_id: ContractId::default(),
+            bytecode: &[1, 2, 3],
+            program_abi: &ProgramABI::MidenVM(()),
+            storage_slots: &storage_slots,
+            artifact: &artifact,
+        };
+
+        let path = bundle.write(&dir.path().join("first")).unwrap();
+        let other_path = bundle.write(&dir.path().join("second")).unwrap();
+        assert_eq!(
+            std::fs::read(&path).unwrap(),
+            std::fs::read(other_path).unwrap()
+        );
+
+        let file = std::fs::File::open(&path).unwrap();
+        let mut archive = tar::Archive::new(GzDecoder::new(file));
+        let names: Vec<_> = archive
+            .entries()
+            .unwrap()
+            .map(|entry| {
+                let entry = entry.unwrap();
+                entry.path().unwrap().to_string_lossy().into_owned()
+            })
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "contract.bin".to_string(),
+                "contract-storage_slots.json".to_string(),
+                format!("contract-deployment-0x{}.json", ContractId::default()),
+                "manifest.json".to_string(),
+            ]
+        );
+    }
+}
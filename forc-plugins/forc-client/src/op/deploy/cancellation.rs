@@ -0,0 +1,91 @@
+use super::DeployCancelled;
+use anyhow::Result;
+use std::{future::Future, sync::Arc};
+use tokio::sync::watch;
+
+/// Cancels the deployment it is given to via [super::DeployOptions::with_cancellation], eg. from a
+/// signal handler.
+///
+/// Clones share the same state, so a clone kept by the caller cancels the deployment given the
+/// original. A cancelled deployment stops waiting for the node and submits no further transaction,
+/// failing with a [DeployCancelled] error. Deployments are never cancelled unless given a token.
+#[derive(Clone, Debug)]
+pub struct CancellationToken {
+    cancelled: Arc<watch::Sender<bool>>,
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        let (cancelled, _) = watch::channel(false);
+        Self {
+            cancelled: Arc::new(cancelled),
+        }
+    }
+}
+
+impl CancellationToken {
+    /// Cancels the deployment given this token or one of its clones.
+    pub fn cancel(&self) {
+        self.cancelled.send_replace(true);
+    }
+
+    /// Whether the token was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        *self.cancelled.borrow()
+    }
+
+    /// Fails with a [DeployCancelled] error if the token was cancelled.
+    pub(crate) fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            return Err(cancelled_error());
+        }
+        Ok(())
+    }
+
+    /// Resolves once the token is cancelled.
+    pub(crate) async fn cancelled(&self) {
+        let mut cancelled = self.cancelled.subscribe();
+        // The sender is held by the token, so the wait can't fail.
+        let _ = cancelled.wait_for(|cancelled| *cancelled).await;
+    }
+
+    /// Runs the given future, failing with a [DeployCancelled] error instead if the token is
+    /// cancelled first.
+    pub(crate) async fn run<T>(&self, future: impl Future<Output = T>) -> Result<T> {
+        tokio::select! {
+            biased;
+            _ = self.cancelled() => Err(cancelled_error()),
+            output = future => Ok(output),
+        }
+    }
+}
+
+/// The error of a deployment cancelled before any transaction was submitted, which the caller
+/// completes with the contracts committed so far.
+fn cancelled_error() -> anyhow::Error {
+    DeployCancelled {
+        committed: Vec::new(),
+        in_flight: None,
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_cancellation_token() {
+        let token = CancellationToken::default();
+        let clone = token.clone();
+        assert!(!token.is_cancelled());
+        assert_eq!(token.run(async { 1 }).await.unwrap(), 1);
+
+        clone.cancel();
+        assert!(token.is_cancelled());
+        assert!(token.check().unwrap_err().is::<DeployCancelled>());
+        // A cancelled token interrupts the futures it runs.
+        let result = token.run(std::future::pending::<()>()).await;
+        assert!(result.unwrap_err().is::<DeployCancelled>());
+    }
+}
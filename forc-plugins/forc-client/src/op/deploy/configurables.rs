@@ -0,0 +1,293 @@
+use crate::util::encode::{Token, Type};
+use anyhow::{anyhow, bail, Result};
+use fuel_abi_types::abi::full_program::{FullConfigurable, FullProgramABI, FullTypeApplication};
+use fuel_tx::Address;
+use fuels_core::codec::{ABIEncoder, EncoderConfig};
+use std::collections::BTreeMap;
+use sway_core::asm_generation::ProgramABI;
+
+/// Values overriding the configurable constants of the deployed contracts, keyed by name.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct ConfigurableOverrides(BTreeMap<String, String>);
+
+impl ConfigurableOverrides {
+    /// Parses configurable overrides in the form `<NAME>=<VALUE>`.
+    pub(crate) fn parse(inputs: &[String]) -> Result<Self> {
+        let mut overrides = BTreeMap::new();
+        for input in inputs {
+            let (name, value) = input.split_once('=').ok_or_else(|| {
+                anyhow!("Invalid configurable `{input}` - must be in the form <NAME>=<VALUE>")
+            })?;
+            if overrides
+                .insert(name.to_string(), value.to_string())
+                .is_some()
+            {
+                bail!("Configurable `{name}` was overridden more than once");
+            }
+        }
+        Ok(Self(overrides))
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn contains(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    /// Ensures that each overridden configurable is declared by at least one of the given ABIs.
+    pub(crate) fn validate<'a>(&self, abis: impl Iterator<Item = &'a ProgramABI>) -> Result<()> {
+        let mut declared = Vec::new();
+        for abi in abis {
+            declared.extend(configurables(abi)?.into_iter().map(|c| c.name));
+        }
+        if let Some(unknown) = self.0.keys().find(|name| !declared.contains(name)) {
+            declared.sort();
+            declared.dedup();
+            bail!(
+                "Unknown configurable `{unknown}`, the deployed contracts declare: {}",
+                if declared.is_empty() {
+                    "none".to_string()
+                } else {
+                    declared.join(", ")
+                }
+            );
+        }
+        Ok(())
+    }
+
+    /// Overwrites the values of the configurables declared in `abi` with their overrides, in
+    /// place within the given bytecode.
+    ///
+    /// Values are ABI encoded, so this requires the new encoding.
+    pub(crate) fn apply(&self, bytecode: &mut [u8], abi: &ProgramABI) -> Result<()> {
+        for configurable in configurables(abi)? {
+            let Some(value) = self.0.get(&configurable.name) else {
+                continue;
+            };
+            let ty = Type::try_from(&configurable.application)?;
+            let token = Token::from_type_and_value(&ty, value).map_err(|e| {
+                anyhow!(
+                    "Invalid value `{value}` for configurable `{}`: {e}",
+                    configurable.name
+                )
+            })?;
+            let encoded = ABIEncoder::new(EncoderConfig::default()).encode(&[token.0])?;
+
+            let offset = configurable.offset as usize;
+            let slot = bytecode
+                .get_mut(offset..offset + encoded.len())
+                .ok_or_else(|| {
+                    anyhow!(
+                        "Configurable `{}` at offset {offset} lies outside of the bytecode",
+                        configurable.name
+                    )
+                })?;
+            slot.copy_from_slice(&encoded);
+        }
+        Ok(())
+    }
+}
+
+/// Whether the given configurable type is the standard library's `Address`.
+fn is_address(application: &FullTypeApplication) -> bool {
+    matches!(
+        application.type_decl.type_field.as_str(),
+        "struct std::address::Address" | "struct Address"
+    )
+}
+
+/// Ensures that the configurable `name` is declared by at least one of the given ABIs, and that
+/// it is an `Address` wherever it is declared.
+pub(crate) fn validate_address_configurable<'a>(
+    name: &str,
+    abis: impl Iterator<Item = &'a ProgramABI>,
+) -> Result<()> {
+    let mut declared = false;
+    for abi in abis {
+        for configurable in configurables(abi)? {
+            if configurable.name != name {
+                continue;
+            }
+            if !is_address(&configurable.application) {
+                bail!(
+                    "Configurable `{name}` is a `{}`, it must be an `Address` to be set to the deployer's address",
+                    configurable.application.type_decl.type_field
+                );
+            }
+            declared = true;
+        }
+    }
+    if !declared {
+        bail!("Unknown configurable `{name}`, none of the deployed contracts declare it");
+    }
+    Ok(())
+}
+
+/// Overwrites the value of the `Address` configurable `name` declared in `abi` with `address`, in
+/// place within the given bytecode. Returns whether `abi` declares the configurable.
+pub(crate) fn set_address_configurable(
+    bytecode: &mut [u8],
+    abi: &ProgramABI,
+    name: &str,
+    address: &Address,
+) -> Result<bool> {
+    let Some(configurable) = configurables(abi)?
+        .into_iter()
+        .find(|configurable| configurable.name == name)
+    else {
+        return Ok(false);
+    };
+    let offset = configurable.offset as usize;
+    let slot = bytecode
+        .get_mut(offset..offset + Address::LEN)
+        .ok_or_else(|| {
+            anyhow!("Configurable `{name}` at offset {offset} lies outside of the bytecode")
+        })?;
+    slot.copy_from_slice(address.as_ref());
+    Ok(true)
+}
+
+/// Returns the names of the integer configurables declared in `abi` whose value in the given
+/// bytecode is zero, which suggests they were left unset.
+///
+/// Booleans are not reported, as `false` is commonly a deliberate value, and neither are
+/// configurables of types whose encoding is not supported.
+pub(crate) fn zeroed_configurables(bytecode: &[u8], abi: &ProgramABI) -> Result<Vec<String>> {
+    let mut zeroed = Vec::new();
+    for configurable in configurables(abi)? {
+        let len = match Type::try_from(&configurable.application) {
+            Ok(Type::U8) => 1,
+            Ok(Type::U16) => 2,
+            Ok(Type::U32) => 4,
+            Ok(Type::U64) => 8,
+            Ok(Type::U256) => 32,
+            _ => continue,
+        };
+        let offset = configurable.offset as usize;
+        if bytecode
+            .get(offset..offset + len)
+            .is_some_and(|value| value.iter().all(|byte| *byte == 0))
+        {
+            zeroed.push(configurable.name);
+        }
+    }
+    Ok(zeroed)
+}
+
+/// Returns the configurables declared in the given ABI.
+pub(super) fn configurables(abi: &ProgramABI) -> Result<Vec<FullConfigurable>> {
+    match abi {
+        ProgramABI::Fuel(abi) => Ok(FullProgramABI::from_counterpart(abi)?.configurables),
+        _ => Ok(Vec::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_abi() -> ProgramABI {
+        let json_abi = r#"{"types":[{"typeId":0,"type":"u64","components":null,"typeParameters":null},
+{"typeId":1,"type":"bool","components":null,"typeParameters":null}],"functions":[],"loggedTypes":[],
+"messagesTypes":[],"configurables":[{"name":"MAX_SUPPLY","configurableType":{"name":"","type":0,
+"typeArguments":null},"offset":8},{"name":"PAUSED","configurableType":{"name":"","type":1,
+"typeArguments":null},"offset":16}]}"#;
+        ProgramABI::Fuel(serde_json::from_str(json_abi).unwrap())
+    }
+
+    #[test]
+    fn test_parse_configurable_overrides() {
+        let overrides = ConfigurableOverrides::parse(&[
+            "MAX_SUPPLY=100".to_string(),
+            "PAUSED=true".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(overrides.0["MAX_SUPPLY"], "100");
+        assert_eq!(overrides.0["PAUSED"], "true");
+
+        assert!(ConfigurableOverrides::parse(&["MAX_SUPPLY".to_string()]).is_err());
+        assert!(ConfigurableOverrides::parse(&[
+            "MAX_SUPPLY=1".to_string(),
+            "MAX_SUPPLY=2".to_string()
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn test_validate_configurable_overrides() {
+        let abi = test_abi();
+        let overrides = ConfigurableOverrides::parse(&["MAX_SUPPLY=100".to_string()]).unwrap();
+        assert!(overrides.validate([&abi].into_iter()).is_ok());
+
+        let overrides = ConfigurableOverrides::parse(&["MIN_SUPPLY=100".to_string()]).unwrap();
+        let err = overrides.validate([&abi].into_iter()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown configurable `MIN_SUPPLY`, the deployed contracts declare: MAX_SUPPLY, PAUSED"
+        );
+    }
+
+    #[test]
+    fn test_apply_configurable_overrides() {
+        let abi = test_abi();
+        let overrides = ConfigurableOverrides::parse(&[
+            "MAX_SUPPLY=258".to_string(),
+            "PAUSED=true".to_string(),
+        ])
+        .unwrap();
+        let mut bytecode = vec![0u8; 24];
+        overrides.apply(&mut bytecode, &abi).unwrap();
+
+        let mut expected = vec![0u8; 24];
+        expected[8..16].copy_from_slice(&258u64.to_be_bytes());
+        expected[16] = 1;
+        assert_eq!(bytecode, expected);
+
+        let overrides = ConfigurableOverrides::parse(&["PAUSED=maybe".to_string()]).unwrap();
+        assert!(overrides.apply(&mut bytecode, &abi).is_err());
+    }
+
+    fn test_address_abi() -> ProgramABI {
+        let json_abi = r#"{"types":[{"typeId":0,"type":"b256","components":null,"typeParameters":null},
+{"typeId":1,"type":"struct std::address::Address","components":[{"name":"bits","type":0,
+"typeArguments":null}],"typeParameters":null},{"typeId":2,"type":"u64","components":null,
+"typeParameters":null}],"functions":[],"loggedTypes":[],"messagesTypes":[],"configurables":[
+{"name":"OWNER","configurableType":{"name":"","type":1,"typeArguments":null},"offset":8},
+{"name":"MAX_SUPPLY","configurableType":{"name":"","type":2,"typeArguments":null},"offset":40}]}"#;
+        ProgramABI::Fuel(serde_json::from_str(json_abi).unwrap())
+    }
+
+    #[test]
+    fn test_validate_address_configurable() {
+        let abi = test_address_abi();
+        assert!(validate_address_configurable("OWNER", [&abi].into_iter()).is_ok());
+        assert!(validate_address_configurable("MAX_SUPPLY", [&abi].into_iter()).is_err());
+        assert!(validate_address_configurable("ADMIN", [&abi].into_iter()).is_err());
+    }
+
+    #[test]
+    fn test_set_address_configurable() {
+        let abi = test_address_abi();
+        let address = Address::new([7; 32]);
+        let mut bytecode = vec![0u8; 48];
+        assert!(set_address_configurable(&mut bytecode, &abi, "OWNER", &address).unwrap());
+        assert_eq!(&bytecode[8..40], address.as_ref());
+        assert!(!set_address_configurable(&mut bytecode, &abi, "ADMIN", &address).unwrap());
+    }
+
+    #[test]
+    fn test_zeroed_configurables() {
+        let abi = test_abi();
+        // `PAUSED` is a bool, which is never reported.
+        let mut bytecode = vec![0u8; 24];
+        assert_eq!(
+            zeroed_configurables(&bytecode, &abi).unwrap(),
+            vec!["MAX_SUPPLY".to_string()]
+        );
+
+        bytecode[15] = 1;
+        assert!(zeroed_configurables(&bytecode, &abi).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,179 @@
+use super::{
+    deployed_code, deployments_output_dir, find_latest_artifact, instance_name, selected_salt,
+    storage_slot_configs, ContractIdentity, ContractSaltMap, SaltStrategy,
+};
+use crate::{cmd, util::node_url::get_node_url};
+use anyhow::{Context, Result};
+use forc_pkg::BuiltPackage;
+use fuel_core_client::client::FuelClient;
+use fuel_tx::{Bytes32, ContractId};
+use fuel_vm::prelude::Contract;
+use serde::Serialize;
+use std::{collections::BTreeMap, str::FromStr, sync::Arc};
+use sway_core::language::parsed::TreeType;
+use tracing::info;
+
+/// How deploying a contract would change the state of the network.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub(crate) enum ContractChange {
+    /// The contract was not deployed to the network before.
+    New { contract_id: ContractId },
+    /// A contract with the same ID is already deployed, so deploying would fail.
+    Unchanged { contract_id: ContractId },
+    /// The contract replaces the one recorded in its latest deployment artifact for the network.
+    Redeploy {
+        contract_id: ContractId,
+        previous_contract_id: ContractId,
+        /// Whether the bytecode differs from the previous contract's on-chain bytecode, if the
+        /// previous contract still exists on-chain.
+        bytecode_changed: Option<bool>,
+    },
+}
+
+impl ContractChange {
+    fn new(
+        contract_id: ContractId,
+        root: Bytes32,
+        already_deployed: bool,
+        previous: Option<(ContractId, Option<Bytes32>)>,
+    ) -> Self {
+        match previous {
+            _ if already_deployed => Self::Unchanged { contract_id },
+            Some((previous_contract_id, previous_root)) => Self::Redeploy {
+                contract_id,
+                previous_contract_id,
+                bytecode_changed: previous_root.map(|previous_root| previous_root != root),
+            },
+            None => Self::New { contract_id },
+        }
+    }
+
+    fn print_summary(&self, name: &str) {
+        match self {
+            Self::New { contract_id } => info!("  + {name}: new contract 0x{contract_id}"),
+            Self::Unchanged { contract_id } => {
+                info!("  = {name}: 0x{contract_id} is already deployed")
+            }
+            Self::Redeploy {
+                contract_id,
+                previous_contract_id,
+                bytecode_changed,
+            } => {
+                let bytecode = match bytecode_changed {
+                    Some(true) => "bytecode changed",
+                    Some(false) => "bytecode unchanged",
+                    None => "previous contract not found on-chain",
+                };
+                info!("  ~ {name}: 0x{contract_id} replaces 0x{previous_contract_id} ({bytecode})")
+            }
+        }
+    }
+}
+
+/// Compares the contracts that would be deployed against the network and their latest recorded
+/// deployments, printing the resulting change set without deploying anything.
+pub(crate) async fn diff(
+    command: &cmd::Deploy,
+    built_pkgs: &[Arc<BuiltPackage>],
+    salt_strategy: Option<&dyn SaltStrategy>,
+    contract_salt_map: Option<&ContractSaltMap>,
+) -> Result<()> {
+    let mut changes = BTreeMap::new();
+    for pkg in built_pkgs {
+        let manifest = &pkg.descriptor.manifest_file;
+        if manifest.check_program_type(&[TreeType::Contract]).is_err() {
+            continue;
+        }
+        let name = manifest.project_name();
+        let node_url = get_node_url(&command.node, &manifest.network)?;
+        let client = FuelClient::new(node_url.clone())?;
+
+        let ((_, salt), _) = selected_salt(command, salt_strategy, contract_salt_map, manifest)?;
+        let output_dir = deployments_output_dir(command, manifest)?;
+        let previous = match find_latest_artifact(&output_dir, name, &node_url) {
+            Some(artifact) => {
+                let previous_contract_id = ContractId::from_str(&artifact.contract_id)
+                    .map_err(anyhow::Error::msg)
+                    .with_context(|| {
+                        format!("Invalid contract ID in the deployment artifact of {name}")
+                    })?;
+                let previous_root = client
+                    .contract(&previous_contract_id)
+                    .await?
+                    .map(|contract| Contract::from(contract.bytecode).root());
+                Some((previous_contract_id, previous_root))
+            }
+            None => None,
+        };
+
+        for storage_slots_file in storage_slot_configs(command) {
+            let (bytecode, storage_slots) = deployed_code(command, pkg, storage_slots_file)?;
+            let ContractIdentity {
+                root, contract_id, ..
+            } = ContractIdentity::new(&bytecode, &storage_slots, &salt);
+            let already_deployed = client.contract(&contract_id).await?.is_some();
+            changes.insert(
+                instance_name(name, storage_slots_file),
+                ContractChange::new(contract_id, root, already_deployed, previous),
+            );
+        }
+    }
+
+    if command.json {
+        println!("{}", serde_json::to_string_pretty(&changes)?);
+    } else {
+        info!("Deployment changes:");
+        for (name, change) in &changes {
+            change.print_summary(name);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contract_change() {
+        let contract_id = ContractId::new([1; 32]);
+        let previous_contract_id = ContractId::new([2; 32]);
+        let root = Bytes32::new([3; 32]);
+
+        assert_eq!(
+            ContractChange::new(contract_id, root, false, None),
+            ContractChange::New { contract_id }
+        );
+        assert_eq!(
+            ContractChange::new(
+                contract_id,
+                root,
+                true,
+                Some((previous_contract_id, Some(root)))
+            ),
+            ContractChange::Unchanged { contract_id }
+        );
+        assert_eq!(
+            ContractChange::new(
+                contract_id,
+                root,
+                false,
+                Some((previous_contract_id, Some(Bytes32::new([4; 32]))))
+            ),
+            ContractChange::Redeploy {
+                contract_id,
+                previous_contract_id,
+                bytecode_changed: Some(true),
+            }
+        );
+        assert_eq!(
+            ContractChange::new(contract_id, root, false, Some((previous_contract_id, None))),
+            ContractChange::Redeploy {
+                contract_id,
+                previous_contract_id,
+                bytecode_changed: None,
+            }
+        );
+    }
+}
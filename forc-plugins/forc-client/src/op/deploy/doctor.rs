@@ -0,0 +1,200 @@
+use crate::{
+    cmd,
+    util::{
+        node_url::get_node_url,
+        pkg::built_pkgs,
+        tx::{
+            bech32_from_secret, collect_account_balances, collect_user_accounts,
+            prompt_forc_wallet_password, select_manual_secret_key,
+        },
+    },
+};
+use anyhow::{anyhow, bail, Result};
+use forc_tracing::{println_action_green, println_action_red};
+use forc_wallet::{balance::AccountsMap, utils::default_wallet_path};
+use fuel_core_client::client::FuelClient;
+use fuels_accounts::provider::Provider;
+use std::{path::PathBuf, time::Duration};
+use sway_core::language::parsed::TreeType;
+
+use super::build_opts_from_cmd;
+
+/// Collects the outcome of each diagnostic check and prints it as it is recorded.
+#[derive(Default)]
+struct Checklist {
+    failed: usize,
+}
+
+impl Checklist {
+    fn record(&mut self, check: &str, result: Result<String>) {
+        match result {
+            Ok(details) => println_action_green("Ok", &format!("{check}: {details}")),
+            Err(e) => {
+                println_action_red("Failed", &format!("{check}: {e}"));
+                self.failed += 1;
+            }
+        }
+    }
+}
+
+/// Diagnoses common environment issues that would make a deployment fail, without deploying
+/// anything.
+///
+/// Checks that the signer can be resolved, that the node is reachable, that the signer's accounts
+/// are funded and that each contract fits within the node's contract size limit.
+pub(crate) async fn doctor(command: &cmd::Deploy) -> Result<()> {
+    let mut checklist = Checklist::default();
+    let curr_dir = if let Some(ref path) = command.pkg.path {
+        PathBuf::from(path)
+    } else {
+        std::env::current_dir()?
+    };
+
+    let contracts = match built_pkgs(&curr_dir, &build_opts_from_cmd(command)) {
+        Ok(built_pkgs) => {
+            let contracts: Vec<_> = built_pkgs
+                .into_iter()
+                .filter(|pkg| {
+                    pkg.descriptor
+                        .manifest_file
+                        .check_program_type(&[TreeType::Contract])
+                        .is_ok()
+                })
+                .collect();
+            let result = if contracts.is_empty() {
+                Err(anyhow!("no deployable contracts found"))
+            } else {
+                Ok(format!("{} contract(s) built", contracts.len()))
+            };
+            checklist.record("Build", result);
+            contracts
+        }
+        Err(e) => {
+            checklist.record("Build", Err(e));
+            Vec::new()
+        }
+    };
+
+    // Resolve the accounts of the signer without asking which one to sign with.
+    let manual_signer = command.default_signer || command.unsigned || command.signing_key.is_some();
+    let accounts = if manual_signer {
+        select_manual_secret_key(
+            command.default_signer || command.unsigned,
+            command.signing_key,
+        )
+        .ok_or_else(|| anyhow!("no signing key provided"))
+        .and_then(|secret_key| bech32_from_secret(&secret_key))
+        .map(|address| AccountsMap::from([(0, address)]))
+    } else {
+        let wallet_path = default_wallet_path();
+        if wallet_path.exists() {
+            checklist.record("Wallet", Ok(format!("found at {wallet_path:?}")));
+            prompt_forc_wallet_password(&wallet_path)
+                .and_then(|password| collect_user_accounts(&wallet_path, &password))
+        } else {
+            Err(anyhow!(
+                "no wallet found at {wallet_path:?}, create one with `forc wallet new`"
+            ))
+        }
+    };
+    let accounts = match accounts {
+        Ok(accounts) if accounts.is_empty() => {
+            checklist.record(
+                "Signer",
+                Err(anyhow!(
+                    "no accounts derived, derive one with `forc wallet account new`"
+                )),
+            );
+            None
+        }
+        Ok(accounts) => {
+            checklist.record(
+                "Signer",
+                Ok(format!("{} account(s) available", accounts.len())),
+            );
+            Some(accounts)
+        }
+        Err(e) => {
+            checklist.record("Signer", Err(e));
+            None
+        }
+    };
+
+    let manifest_network = match &contracts[..] {
+        [pkg] => pkg.descriptor.manifest_file.network.clone(),
+        _ => None,
+    };
+    let node_url = get_node_url(&command.node, &manifest_network)?;
+    let client = FuelClient::new(&node_url)?;
+    let node_timeout = Duration::from_millis(command.node_timeout_ms);
+    let health = tokio::time::timeout(node_timeout, client.health())
+        .await
+        .unwrap_or_else(|_| {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                format!("no response within {}ms", command.node_timeout_ms),
+            ))
+        });
+    let provider = match health {
+        Ok(true) => match Provider::connect(&node_url).await {
+            Ok(provider) => {
+                checklist.record("Node", Ok(format!("reachable at {node_url}")));
+                Some(provider)
+            }
+            Err(e) => {
+                checklist.record("Node", Err(e.into()));
+                None
+            }
+        },
+        Ok(false) => {
+            checklist.record("Node", Err(anyhow!("node at {node_url} is not healthy")));
+            None
+        }
+        Err(e) => {
+            checklist.record("Node", Err(anyhow!("could not reach {node_url}: {e}")));
+            None
+        }
+    };
+
+    if let Some(provider) = provider {
+        if let Some(accounts) = &accounts {
+            let balance = collect_account_balances(accounts, &provider)
+                .await
+                .and_then(|account_balances| {
+                    let total_balance = account_balances
+                        .iter()
+                        .flat_map(|account| account.values())
+                        .sum::<u64>();
+                    if total_balance == 0 {
+                        bail!("the signer's accounts have no funds to pay for the deployment")
+                    }
+                    Ok(format!("total balance of {total_balance}"))
+                });
+            checklist.record("Balance", balance);
+        }
+
+        let contract_max_size = provider
+            .consensus_parameters()
+            .contract_params()
+            .contract_max_size();
+        for pkg in &contracts {
+            let size = pkg.bytecode.bytes.len() as u64;
+            let name = pkg.descriptor.manifest_file.project_name();
+            let result = if size <= contract_max_size {
+                Ok(format!(
+                    "{size} bytes, within the node limit of {contract_max_size} bytes"
+                ))
+            } else {
+                Err(anyhow!(
+                    "{size} bytes, exceeds the node limit of {contract_max_size} bytes"
+                ))
+            };
+            checklist.record(&format!("Contract size ({name})"), result);
+        }
+    }
+
+    if checklist.failed > 0 {
+        bail!("{} check(s) failed", checklist.failed);
+    }
+    Ok(())
+}
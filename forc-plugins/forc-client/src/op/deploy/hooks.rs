@@ -0,0 +1,102 @@
+use anyhow::{bail, Context, Result};
+use fuel_tx::ContractId;
+use std::process::Command;
+
+/// Runs the `--pre-deploy-cmd` or `--post-deploy-cmd` hook `cmd` through the platform shell,
+/// with the given additional environment variables.
+pub(crate) fn run(hook: &str, cmd: &str, envs: &[(String, String)]) -> Result<()> {
+    let mut shell = if cfg!(windows) {
+        let mut shell = Command::new("cmd");
+        shell.arg("/C");
+        shell
+    } else {
+        let mut shell = Command::new("sh");
+        shell.arg("-c");
+        shell
+    };
+    let status = shell
+        .arg(cmd)
+        .envs(envs.iter().map(|(key, value)| (key, value)))
+        .status()
+        .with_context(|| format!("Failed to run the {hook} command `{cmd}`"))?;
+    if !status.success() {
+        bail!("The {hook} command `{cmd}` failed with {status}");
+    }
+    Ok(())
+}
+
+/// Returns the environment variables describing the deployed contracts to the post-deploy hook:
+/// - `FORC_DEPLOY_NODE_URL`: the URL of the node the contracts were deployed to.
+/// - `FORC_DEPLOY_CONTRACTS`: the names of the deployed contracts, separated by spaces.
+/// - `FORC_DEPLOY_CONTRACT_ID_<NAME>`: the ID of each contract, where `<NAME>` is its name in
+///   uppercase with dashes replaced by underscores.
+pub(crate) fn post_deploy_envs(
+    node_url: &str,
+    deployed: &[(String, ContractId)],
+) -> Vec<(String, String)> {
+    let names = deployed
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let mut envs = vec![
+        ("FORC_DEPLOY_NODE_URL".to_string(), node_url.to_string()),
+        ("FORC_DEPLOY_CONTRACTS".to_string(), names),
+    ];
+    envs.extend(deployed.iter().map(|(name, contract_id)| {
+        (
+            format!(
+                "FORC_DEPLOY_CONTRACT_ID_{}",
+                name.to_uppercase().replace('-', "_")
+            ),
+            format!("0x{contract_id}"),
+        )
+    }));
+    envs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_post_deploy_envs() {
+        let deployed = vec![
+            ("token".to_string(), ContractId::new([1; 32])),
+            ("token-vault".to_string(), ContractId::new([2; 32])),
+        ];
+        let envs = post_deploy_envs("http://127.0.0.1:4000/v1/graphql", &deployed);
+        assert_eq!(
+            envs,
+            vec![
+                (
+                    "FORC_DEPLOY_NODE_URL".to_string(),
+                    "http://127.0.0.1:4000/v1/graphql".to_string()
+                ),
+                (
+                    "FORC_DEPLOY_CONTRACTS".to_string(),
+                    "token token-vault".to_string()
+                ),
+                (
+                    "FORC_DEPLOY_CONTRACT_ID_TOKEN".to_string(),
+                    format!("0x{}", ContractId::new([1; 32]))
+                ),
+                (
+                    "FORC_DEPLOY_CONTRACT_ID_TOKEN_VAULT".to_string(),
+                    format!("0x{}", ContractId::new([2; 32]))
+                ),
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_run_hook() {
+        let envs = vec![("FORC_DEPLOY_TEST".to_string(), "1".to_string())];
+        run("post-deploy", r#"test "$FORC_DEPLOY_TEST" = 1"#, &envs).unwrap();
+        let err = run("pre-deploy", "exit 3", &[]).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("pre-deploy command `exit 3` failed"));
+    }
+}
@@ -0,0 +1,186 @@
+use super::{deployments_output_dir, DeploymentArtifact};
+use crate::cmd;
+use anyhow::Result;
+use forc_pkg::manifest::{GenericManifestFile, ManifestFile};
+use forc_tracing::println_warning;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use sway_core::language::parsed::TreeType;
+use tracing::info;
+
+/// A deployment artifact read back from a deployments directory.
+#[derive(Debug, Serialize)]
+struct ListedDeployment {
+    /// The name of the deployed contract.
+    contract: String,
+    /// The path of the artifact.
+    path: PathBuf,
+    #[serde(flatten)]
+    artifact: DeploymentArtifact,
+}
+
+impl ListedDeployment {
+    /// The name of the network deployed to, falling back to its endpoint.
+    fn network(&self) -> &str {
+        self.artifact
+            .network_name
+            .as_deref()
+            .unwrap_or(&self.artifact.network_endpoint)
+    }
+
+    /// Whether the deployment matches the `--list-network` and `--list-contract` filters.
+    fn matches(&self, network: Option<&str>, contract: Option<&str>) -> bool {
+        let network_matches = network.map_or(true, |network| {
+            self.artifact.network_endpoint == network
+                || self.artifact.network_name.as_deref() == Some(network)
+        });
+        network_matches && contract.map_or(true, |contract| self.contract == contract)
+    }
+}
+
+/// Reads the plain-text deployment artifacts of the contract `pkg_name` from `output_dir`,
+/// skipping those that can't be parsed with a warning.
+fn read_deployments(output_dir: &Path, pkg_name: &str) -> Result<Vec<ListedDeployment>> {
+    if !output_dir.exists() {
+        return Ok(Vec::new());
+    }
+    let artifact_prefix = format!("{pkg_name}-deployment-0x");
+    let mut deployments = Vec::new();
+    for entry in std::fs::read_dir(output_dir)? {
+        let path = entry?.path();
+        let is_artifact = path.extension().is_some_and(|ext| ext == "json")
+            && path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with(&artifact_prefix));
+        if !is_artifact {
+            continue;
+        }
+        let artifact = std::fs::read_to_string(&path)
+            .map_err(anyhow::Error::from)
+            .and_then(|artifact| Ok(serde_json::from_str::<DeploymentArtifact>(&artifact)?));
+        match artifact {
+            Ok(artifact) => deployments.push(ListedDeployment {
+                contract: pkg_name.to_string(),
+                path,
+                artifact,
+            }),
+            Err(e) => println_warning(&format!(
+                "Skipping malformed deployment artifact {}: {e}",
+                path.display()
+            )),
+        }
+    }
+    Ok(deployments)
+}
+
+/// Prints the deployments recorded in the deployment artifacts of the contract(s), without
+/// connecting to a node.
+///
+/// Deployments are listed by contract name and block height, optionally filtered by network and
+/// contract name.
+pub(crate) fn list(command: &cmd::Deploy) -> Result<()> {
+    let curr_dir = match &command.pkg.path {
+        Some(path) => PathBuf::from(path),
+        None => std::env::current_dir()?,
+    };
+    let mut deployments = Vec::new();
+    for manifest in ManifestFile::from_dir(&curr_dir)?
+        .member_manifests()?
+        .values()
+    {
+        if manifest.program_type()? != TreeType::Contract {
+            continue;
+        }
+        let output_dir = deployments_output_dir(command, manifest)?;
+        deployments.extend(read_deployments(&output_dir, manifest.project_name())?);
+    }
+    deployments.retain(|deployment| {
+        deployment.matches(
+            command.list_network.as_deref(),
+            command.list_contract.as_deref(),
+        )
+    });
+    deployments.sort_by(|a, b| {
+        (&a.contract, a.artifact.deployed_block_height)
+            .cmp(&(&b.contract, b.artifact.deployed_block_height))
+    });
+
+    if command.json {
+        println!("{}", serde_json::to_string_pretty(&deployments)?);
+        return Ok(());
+    }
+    if deployments.is_empty() {
+        info!("No deployments found");
+        return Ok(());
+    }
+    let name_width = deployments
+        .iter()
+        .map(|deployment| deployment.contract.len())
+        .max()
+        .unwrap_or_default()
+        .max("CONTRACT".len());
+    let network_width = deployments
+        .iter()
+        .map(|deployment| deployment.network().len())
+        .max()
+        .unwrap_or_default()
+        .max("NETWORK".len());
+    println!(
+        "{:name_width$}  {:network_width$}  {:>12}  CONTRACT ID",
+        "CONTRACT", "NETWORK", "BLOCK"
+    );
+    for deployment in &deployments {
+        println!(
+            "{:name_width$}  {:network_width$}  {:>12}  {}",
+            deployment.contract,
+            deployment.network(),
+            deployment.artifact.deployed_block_height,
+            deployment.artifact.contract_id
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::op::deploy::test::test_deployment_artifact;
+    use fuel_tx::ContractId;
+
+    #[test]
+    fn test_read_deployments_skips_malformed_artifacts() {
+        let output_dir = tempfile::tempdir().unwrap();
+        let artifact = test_deployment_artifact();
+        artifact
+            .to_file(output_dir.path(), "counter", ContractId::default())
+            .unwrap();
+        std::fs::write(
+            output_dir
+                .path()
+                .join("counter-deployment-0xmalformed.json"),
+            "{}",
+        )
+        .unwrap();
+        std::fs::write(output_dir.path().join("other-deployment-0x00.json"), "{}").unwrap();
+
+        let deployments = read_deployments(output_dir.path(), "counter").unwrap();
+        assert_eq!(deployments.len(), 1);
+        assert_eq!(deployments[0].contract, "counter");
+        assert_eq!(deployments[0].artifact.contract_id, artifact.contract_id);
+    }
+
+    #[test]
+    fn test_listed_deployment_matches() {
+        let deployment = ListedDeployment {
+            contract: "counter".to_string(),
+            path: PathBuf::new(),
+            artifact: test_deployment_artifact(),
+        };
+        let endpoint = deployment.artifact.network_endpoint.clone();
+        assert!(deployment.matches(None, None));
+        assert!(deployment.matches(Some(&endpoint), Some("counter")));
+        assert!(!deployment.matches(Some("mainnet"), None));
+        assert!(!deployment.matches(None, Some("token")));
+    }
+}
@@ -0,0 +1,4006 @@
+mod abi_diff;
+mod bundle;
+mod cancellation;
+mod configurables;
+mod contract_diff;
+mod doctor;
+mod hooks;
+mod list;
+mod policy;
+mod predicate;
+mod remote;
+mod salt_strategy;
+mod schema;
+mod signature;
+mod size_breakdown;
+mod unsigned;
+mod warnings;
+
+use self::{
+    abi_diff::AbiDiff,
+    configurables::{
+        set_address_configurable, validate_address_configurable, zeroed_configurables,
+        ConfigurableOverrides,
+    },
+    salt_strategy::ManifestSalt,
+    size_breakdown::SizeBreakdown,
+    warnings::{collect_warnings, package_warnings},
+};
+pub use self::{
+    cancellation::CancellationToken,
+    policy::DeployPolicy,
+    salt_strategy::{DefaultSalt, ExplicitSalts, RandomSalt, SaltSource, SaltStrategy},
+    signature::{verify_artifact_signature, ArtifactSignature},
+    unsigned::UnsignedDeployment,
+    warnings::BuildWarning,
+};
+use crate::{
+    cmd,
+    constants::{
+        BLOCK_INTERVAL_SAMPLE_BLOCKS, DEPLOYMENT_ARTIFACT_SCHEMA_VERSION, GAS_PRICE_BLOCK_HORIZON,
+        MAX_SALT_BUMPS, MIN_SUBMIT_TIMEOUT_BLOCKS, REHEARSALS_DIR, SUPPORTED_FUEL_CORE_VERSION,
+    },
+    util::{
+        local_node::LocalNode,
+        node_url::{get_node_url, is_local_node_url, known_network_name},
+        pkg::built_pkgs,
+        tx::{
+            bech32_from_secret, poll_tx_status, prompt_forc_wallet_password,
+            secret_key_from_forc_wallet_path, select_secret_key, validate_hd_path,
+            WalletSelectionMode,
+        },
+    },
+    NodeTarget,
+};
+use anyhow::{bail, Context, Result};
+use eth_keystore::KeystoreError;
+use forc_pkg::manifest::{GenericManifestFile, ManifestFile, NetworkSalt};
+use forc_pkg::{self as pkg, PackageManifestFile};
+use forc_tracing::println_warning;
+use forc_util::default_output_directory;
+use forc_wallet::utils::default_wallet_path;
+use fuel_abi_types::abi::program as program_abi;
+use fuel_core_client::client::types::TransactionStatus;
+use fuel_core_client::client::FuelClient;
+use fuel_crypto::{fuel_types::ChainId, Hasher};
+use fuel_tx::{ConsensusParameters, Salt, UniqueIdentifier};
+use fuel_vm::prelude::*;
+use fuels_accounts::{provider::Provider, wallet::WalletUnlocked, Account};
+use fuels_core::types::{
+    coin_type::CoinType,
+    coin_type_id::CoinTypeId,
+    transaction::TxPolicies,
+    transaction_builders::{CreateTransactionBuilder, TransactionBuilder as _},
+};
+use futures::Stream;
+use pkg::{manifest::build_profile::ExperimentalFlags, BuildProfile, BuiltPackage};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+use std::{
+    fmt,
+    io::{IsTerminal, Write},
+    time::Duration,
+};
+use sway_core::language::parsed::TreeType;
+use sway_core::{asm_generation::ProgramABI, BuildTarget};
+use tracing::info;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct DeployedContract {
+    pub id: fuel_tx::ContractId,
+    /// The receipts of the deployment transaction, only captured with `--receipts`.
+    pub receipts: Option<Vec<fuel_tx::Receipt>>,
+}
+
+/// The error returned by [deploy] when the deployment is cancelled, eg. by the user interrupting
+/// `forc deploy` with Ctrl-C.
+#[derive(Debug)]
+pub struct DeployCancelled {
+    /// The contracts that were committed before the deployment was cancelled.
+    pub committed: Vec<fuel_tx::ContractId>,
+    /// The transaction that was awaiting commit when the deployment was cancelled, if any.
+    pub in_flight: Option<fuel_tx::TxId>,
+}
+
+impl DeployCancelled {
+    /// The exit code used by `forc deploy` when the deployment is cancelled.
+    pub const EXIT_CODE: i32 = 130;
+}
+
+impl fmt::Display for DeployCancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Deployment cancelled by user")?;
+        if !self.committed.is_empty() {
+            write!(f, "\nContracts committed before cancellation:")?;
+            for contract_id in &self.committed {
+                write!(f, "\n  - 0x{contract_id}")?;
+            }
+        }
+        if let Some(tx_id) = &self.in_flight {
+            write!(
+                f,
+                "\nTransaction 0x{tx_id} was awaiting commit and may still be included in a block"
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for DeployCancelled {}
+
+/// The file extension of deployment artifacts written with `--encrypt-artifact`.
+const ENCRYPTED_ARTIFACT_EXTENSION: &str = "json.enc";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeploymentArtifact {
+    /// The version of the artifact format, [DEPLOYMENT_ARTIFACT_SCHEMA_VERSION] when written by
+    /// this version of forc-client. Artifacts written before it was recorded read as version 0.
+    #[serde(default)]
+    schema_version: u32,
+    transaction_id: String,
+    salt: String,
+    network_endpoint: String,
+    /// The name of the network the contract was deployed to, from `--network-name` or the
+    /// well-known network at the endpoint.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    network_name: Option<String>,
+    chain_id: ChainId,
+    contract_id: String,
+    deployment_size: usize,
+    deployed_block_height: u32,
+    /// When the artifact was created, in seconds since the Unix epoch.
+    ///
+    /// Taken from the `SOURCE_DATE_EPOCH` environment variable if set, so that artifacts can be
+    /// reproduced byte for byte, eg. in CI. Otherwise, the current time is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    created_at: Option<u64>,
+    /// The ID of the genesis block of the chain the contract was deployed to, used to detect
+    /// that the network has been reset since.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    genesis_block_id: Option<String>,
+    /// The ABI of the deployed contract, used to check the compatibility of later builds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    abi: Option<program_abi::ProgramABI>,
+    /// The number of storage slots the contract was deployed with.
+    #[serde(default)]
+    storage_slot_count: usize,
+    /// The sorted storage slots the contract was deployed with, recorded with
+    /// `--record-storage-slots` so that its state root can be reproduced from the artifact.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    storage_slots: Option<Vec<StorageSlot>>,
+    /// The fork the deployment was rehearsed against, for `--fork` deployments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rehearsal: Option<Rehearsal>,
+    /// The metadata the deployment was tagged with via `--tag`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    tags: BTreeMap<String, String>,
+    /// Where the salt was taken from, eg. `--salt`, the manifest or a random salt.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    salt_source: Option<SaltSource>,
+}
+
+/// Describes the fork a `--fork` deployment was rehearsed against.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Rehearsal {
+    /// The URL of the node running the fork.
+    fork: String,
+    /// The block height of the fork before the deployment was submitted.
+    fork_block_height: u32,
+}
+
+impl DeploymentArtifact {
+    pub fn to_file(
+        &self,
+        output_dir: &Path,
+        pkg_name: &str,
+        contract_id: ContractId,
+    ) -> Result<PathBuf> {
+        if !output_dir.exists() {
+            std::fs::create_dir_all(output_dir)?;
+        }
+
+        let deployment_artifact_json = format!("{pkg_name}-deployment-0x{contract_id}");
+        let deployments_path = output_dir
+            .join(deployment_artifact_json)
+            .with_extension("json");
+        let deployments_file = std::fs::File::create(&deployments_path)?;
+        serde_json::to_writer_pretty(&deployments_file, &self)?;
+        Ok(deployments_path)
+    }
+
+    /// Encrypts the artifact with the given password and writes it to `output_dir`, returning
+    /// the path of the written file.
+    ///
+    /// The artifact is stored in the keystore format used by forc-wallet, which authenticates
+    /// the ciphertext so that tampering is detected on decryption.
+    pub fn to_encrypted_file(
+        &self,
+        output_dir: &Path,
+        pkg_name: &str,
+        contract_id: ContractId,
+        password: &str,
+    ) -> Result<PathBuf> {
+        if !output_dir.exists() {
+            std::fs::create_dir_all(output_dir)?;
+        }
+
+        let file_name =
+            format!("{pkg_name}-deployment-0x{contract_id}.{ENCRYPTED_ARTIFACT_EXTENSION}");
+        let deployment_artifact_json = serde_json::to_vec(&self)?;
+        eth_keystore::encrypt_key(
+            output_dir,
+            &mut rand::thread_rng(),
+            deployment_artifact_json,
+            password,
+            Some(&file_name),
+        )?;
+        Ok(output_dir.join(file_name))
+    }
+
+    /// Reads and decrypts an artifact written by [DeploymentArtifact::to_encrypted_file].
+    pub fn from_encrypted_file(path: &Path, password: &str) -> Result<Self> {
+        let deployment_artifact_json =
+            eth_keystore::decrypt_key(path, password).map_err(|e| match e {
+                KeystoreError::MacMismatch => anyhow::anyhow!(
+                    "Failed to decrypt deployment artifact at {path:?}. Either the password is wrong or the file has been tampered with"
+                ),
+                e => e.into(),
+            })?;
+        let deployment_artifact = serde_json::from_slice(&deployment_artifact_json)?;
+        Ok(deployment_artifact)
+    }
+}
+
+/// The error returned when the salts passed via `--salt` or `--salt-file` are invalid.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SaltParseError {
+    /// The salt is shorter than 32 bytes.
+    TooShort { salt: String, len: usize },
+    /// The salt is longer than 32 bytes.
+    TooLong { salt: String, len: usize },
+    /// The salt contains a character that is not a hexadecimal digit.
+    BadHex { salt: String, invalid: char },
+    /// The salt has an odd number of hexadecimal digits, so it is not a whole number of bytes.
+    OddLength(String),
+    /// A salt given when deploying a workspace is not in the `<CONTRACT_NAME>:<SALT>` form.
+    WrongFormatForWorkspace,
+    /// More than one salt was given for the same contract.
+    Duplicate {
+        contract: String,
+        first: Salt,
+        second: Salt,
+    },
+    /// The salt of a contract is also declared for it as a contract dependency in a manifest.
+    ConflictWithManifest {
+        contract: String,
+        manifest: String,
+        existing: Salt,
+        declared: Salt,
+    },
+}
+
+impl fmt::Display for SaltParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooShort { salt, len } => {
+                write!(f, "Invalid salt '{salt}' - salt must be 32 bytes long, but it is {len} bytes")?;
+                if *len == 20 {
+                    write!(f, ". This looks like an Ethereum address, not a salt")?;
+                }
+                Ok(())
+            }
+            Self::TooLong { salt, len } => write!(f, "Invalid salt '{salt}' - salt must be 32 bytes long, but it is {len} bytes"),
+            Self::BadHex { salt, invalid } => {
+                write!(f, "Invalid salt '{salt}' - salt must be a hexadecimal literal, but it contains '{invalid}'")?;
+                if salt.starts_with("fuel1") {
+                    write!(f, ". This looks like a bech32 address, not a salt")?;
+                }
+                Ok(())
+            }
+            Self::OddLength(salt) => write!(f, "Invalid salt '{salt}' - salt must have an even number of hexadecimal digits, 2 per byte"),
+            Self::WrongFormatForWorkspace => write!(f, "Invalid salt provided - salt must be in the form <CONTRACT_NAME>:<SALT> when deploying a workspace"),
+            Self::Duplicate { contract, first, second } => {
+                write!(f, "2 salts provided for contract '{contract}':\n  {first}\n  {second}")
+            }
+            Self::ConflictWithManifest { contract, manifest, existing, declared } => write!(
+                f,
+                "Redeclaration of salt using the option '--salt' while a salt exists for contract '{contract}' \
+                under the contract dependencies of the Forc.toml manifest for '{manifest}'\n\
+                Existing salt: '0x{existing}',\nYou declared: '0x{declared}'\n",
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SaltParseError {}
+
+/// Parses a 256-bit hexadecimal salt, with or without the `0x` prefix.
+fn parse_salt(salt: &str) -> std::result::Result<Salt, SaltParseError> {
+    let digits = salt.strip_prefix("0x").unwrap_or(salt);
+    if let Some(invalid) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(SaltParseError::BadHex {
+            salt: salt.to_string(),
+            invalid,
+        });
+    }
+    if digits.len() % 2 != 0 {
+        return Err(SaltParseError::OddLength(salt.to_string()));
+    }
+    let bytes = hex::decode(digits).expect("digits were checked above");
+    let bytes: [u8; Salt::LEN] = match bytes.len() {
+        len if len < Salt::LEN => {
+            return Err(SaltParseError::TooShort {
+                salt: salt.to_string(),
+                len,
+            })
+        }
+        len if len > Salt::LEN => {
+            return Err(SaltParseError::TooLong {
+                salt: salt.to_string(),
+                len,
+            })
+        }
+        _ => bytes.try_into().expect("length was checked above"),
+    };
+    Ok(Salt::new(bytes))
+}
+
+type ContractSaltMap = BTreeMap<String, Salt>;
+
+/// Takes the contract member salt inputs passed via the --salt option, validates them against
+/// the manifests and returns a ContractSaltMap (BTreeMap of contract names to salts).
+fn validate_and_parse_salts<'a>(
+    salt_args: &[String],
+    manifests: impl Iterator<Item = &'a PackageManifestFile>,
+) -> std::result::Result<ContractSaltMap, SaltParseError> {
+    let mut contract_salt_map = BTreeMap::default();
+
+    // Parse all the salt arguments first, and exit if there are errors in this step.
+    for salt_arg in salt_args {
+        if let Some((given_contract_name, salt)) = salt_arg.split_once(':') {
+            let salt = parse_salt(salt)?;
+
+            if let Some(old) = contract_salt_map.insert(given_contract_name.to_string(), salt) {
+                return Err(SaltParseError::Duplicate {
+                    contract: given_contract_name.to_string(),
+                    first: old,
+                    second: salt,
+                });
+            };
+        } else {
+            return Err(SaltParseError::WrongFormatForWorkspace);
+        }
+    }
+
+    for manifest in manifests {
+        for (dep_name, contract_dep) in manifest.contract_deps() {
+            let dep_pkg_name = contract_dep.dependency.package().unwrap_or(dep_name);
+            if let Some(declared_salt) = contract_salt_map.get(dep_pkg_name) {
+                return Err(SaltParseError::ConflictWithManifest {
+                    contract: dep_pkg_name.to_string(),
+                    manifest: manifest.project_name().to_string(),
+                    existing: contract_dep.salt.0,
+                    declared: *declared_salt,
+                });
+            }
+        }
+    }
+
+    Ok(contract_salt_map)
+}
+
+/// Builds and deploys contract(s). If the given path corresponds to a workspace, all deployable members
+/// will be built and deployed.
+///
+/// Upon success, returns the ID of each deployed contract in order of deployment.
+///
+/// Workspace members are deployed one at a time in compilation order, so contract dependencies
+/// are deployed before the contracts depending on them. Each deployment is committed before the
+/// next one is submitted, so the returned order is stable for a given workspace.
+///
+/// When deploying a single contract, only that contract's ID is returned.
+pub async fn deploy(command: cmd::Deploy) -> Result<Vec<DeployedContract>> {
+    deploy_with_options(command, DeployOptions::default()).await
+}
+
+/// Builds and deploys contract(s) like [deploy], customized by the given options.
+pub async fn deploy_with_options(
+    command: cmd::Deploy,
+    options: DeployOptions,
+) -> Result<Vec<DeployedContract>> {
+    let deployment = prepare_deployment(command, options).await?;
+    run_deployment(deployment).await?.into_result()
+}
+
+/// A hook customizing the transaction deploying each contract, eg. to add inputs, outputs or
+/// witnesses for predicates paying the fee.
+///
+/// The hook receives the prepared transaction builder once the signer's witness, and the fee
+/// payer's if any, are added, and before the inputs covering the fee and the change output are
+/// added, so that the fee accounts for the hook's changes.
+pub type TxBuilderHook =
+    Box<dyn Fn(&mut CreateTransactionBuilder) -> Result<()> + Send + Sync + 'static>;
+
+/// Customizes a deployment made through [deploy_with_options], [deploy_report] or
+/// [deploy_stream] beyond what the command's flags allow.
+///
+/// The options are set by chaining the `with_*` methods on the default options, and can be
+/// combined with each other.
+#[derive(Default)]
+pub struct DeployOptions {
+    policy: Option<DeployPolicy>,
+    salt_strategy: Option<Box<dyn SaltStrategy>>,
+    tx_builder_hook: Option<TxBuilderHook>,
+    cancellation: CancellationToken,
+}
+
+impl DeployOptions {
+    /// Deploys with the timeouts and retries of the given policy rather than those of the
+    /// command's flags.
+    pub fn with_policy(mut self, policy: DeployPolicy) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
+    /// Selects the salt of each contract with the given strategy rather than with the command's
+    /// salt flags, which must not be set.
+    pub fn with_salt_strategy(mut self, salt_strategy: impl SaltStrategy + 'static) -> Self {
+        self.salt_strategy = Some(Box::new(salt_strategy));
+        self
+    }
+
+    /// Lets the given hook customize each deployment transaction before it is funded and built.
+    pub fn with_tx_builder_hook(
+        mut self,
+        tx_builder_hook: impl Fn(&mut CreateTransactionBuilder) -> Result<()> + Send + Sync + 'static,
+    ) -> Self {
+        self.tx_builder_hook = Some(Box::new(tx_builder_hook));
+        self
+    }
+
+    /// Lets the deployment be cancelled through the given token or one of its clones.
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+}
+
+/// The outcome of deploying each contract, as returned by [deploy_report].
+#[derive(Debug, Default)]
+pub struct DeployReport {
+    /// The deployed contracts, in order of deployment.
+    pub succeeded: Vec<DeployedContract>,
+    /// The name of each contract that failed to deploy, along with the reason.
+    pub failed: Vec<(String, anyhow::Error)>,
+    /// The warnings emitted while building the contract(s).
+    pub warnings: Vec<BuildWarning>,
+}
+
+impl DeployReport {
+    /// Returns the deployed contracts if all deployments succeeded, or an error describing the
+    /// failed ones otherwise.
+    pub fn into_result(self) -> Result<Vec<DeployedContract>> {
+        let mut failed = self.failed;
+        match failed.len() {
+            0 => Ok(self.succeeded),
+            1 => Err(failed.remove(0).1),
+            _ => {
+                let failures = failed
+                    .iter()
+                    .map(|(name, e)| format!("  {name}: {e}"))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                bail!(
+                    "{} of {} contracts failed to deploy:\n{failures}",
+                    failed.len(),
+                    failed.len() + self.succeeded.len()
+                )
+            }
+        }
+    }
+}
+
+/// Builds and deploys contract(s) like [deploy], but reports which contracts were deployed and
+/// which failed rather than returning the first error.
+///
+/// Deployment stops at the first failure unless `--keep-going` is passed. Errors preventing the
+/// deployment from starting, as well as cancellations, are returned as errors.
+pub async fn deploy_report(command: cmd::Deploy, options: DeployOptions) -> Result<DeployReport> {
+    let deployment = prepare_deployment(command, options).await?;
+    run_deployment(deployment).await
+}
+
+/// Deploys each contract of the given deployment, if any, reporting which were deployed and which
+/// failed.
+async fn run_deployment(deployment: Option<Deployment>) -> Result<DeployReport> {
+    let mut report = DeployReport::default();
+    let Some(mut deployment) = deployment else {
+        return Ok(report);
+    };
+    report.warnings = std::mem::take(&mut deployment.warnings);
+    while let Some((name, result)) = deployment.deploy_next().await {
+        match result {
+            Ok(contract) => report.succeeded.push(contract),
+            Err(e) if e.is::<DeployCancelled>() => return Err(e),
+            Err(e) => {
+                report.failed.push((name, e));
+                if !deployment.command.keep_going {
+                    return Ok(report);
+                }
+            }
+        }
+    }
+    deployment.finish()?;
+    Ok(report)
+}
+
+/// Builds the contract(s) like [deploy], but returns a stream yielding each deployed contract as
+/// soon as its deployment is committed, rather than once all of them are deployed.
+///
+/// Building, salt validation and password prompts all happen before the stream is returned. The
+/// stream ends after the first error it yields, unless `--keep-going` is passed.
+pub async fn deploy_stream(
+    command: cmd::Deploy,
+    options: DeployOptions,
+) -> Result<impl Stream<Item = Result<DeployedContract>>> {
+    let deployment = prepare_deployment(command, options).await?;
+    Ok(futures::stream::unfold(
+        deployment,
+        |deployment| async move {
+            let mut deployment = deployment?;
+            match deployment.deploy_next().await {
+                Some((_, Ok(contract))) => Some((Ok(contract), Some(deployment))),
+                Some((_, Err(e))) => {
+                    let keep_going = deployment.command.keep_going && !e.is::<DeployCancelled>();
+                    Some((Err(e), keep_going.then_some(deployment)))
+                }
+                None => deployment.finish().err().map(|e| (Err(e), None)),
+            }
+        },
+    ))
+}
+
+/// The state of an in-progress deployment, shared between the items of [deploy_stream].
+struct Deployment {
+    command: cmd::Deploy,
+    /// Keeps the node spawned via `--spawn-node` alive until the deployment is over.
+    _local_node: Option<LocalNode>,
+    /// The packages to deploy, along with the `--override-storage-slots` file of each instance.
+    instances: std::vec::IntoIter<(Arc<BuiltPackage>, Option<String>)>,
+    contract_salt_map: Option<ContractSaltMap>,
+    /// The strategy selecting the salt of each contract in place of the salt flags, if set via
+    /// [DeployOptions::with_salt_strategy].
+    salt_strategy: Option<Box<dyn SaltStrategy>>,
+    context: DeployContext,
+    committed: Vec<ContractId>,
+    /// The name and ID of each deployed contract, passed to the `--post-deploy-cmd`.
+    deployed: Vec<(String, ContractId)>,
+    /// The URL of the node the last contract was deployed to.
+    node_url: Option<String>,
+    deployed_salts: ContractSaltMap,
+    /// The warnings emitted while building the contract(s), handed over to the [DeployReport].
+    warnings: Vec<BuildWarning>,
+}
+
+/// The state shared by the deployment of each contract, passed to [deploy_instance].
+struct DeployContext {
+    policy: DeployPolicy,
+    /// The tip paid by each deployment transaction, if any.
+    tip: Option<u64>,
+    wallet_mode: WalletSelectionMode,
+    artifact_password: Option<String>,
+    /// The hook customizing each deployment transaction, if set via
+    /// [DeployOptions::with_tx_builder_hook].
+    tx_builder_hook: Option<TxBuilderHook>,
+    node_info_cache: NodeInfoCache,
+    spent_inputs: SpentInputs,
+    /// [DeployOptions::with_cancellation].
+    cancellation: CancellationToken,
+}
+
+impl Deployment {
+    /// Deploys the next contract, returning its name along with the outcome of its deployment,
+    /// or `None` once all contracts were deployed.
+    async fn deploy_next(&mut self) -> Option<(String, Result<DeployedContract>)> {
+        let (pkg, storage_slots_file) = loop {
+            let (pkg, storage_slots_file) = self.instances.next()?;
+            if pkg
+                .descriptor
+                .manifest_file
+                .check_program_type(&[TreeType::Contract])
+                .is_ok()
+            {
+                break (pkg, storage_slots_file);
+            }
+        };
+        let storage_slots_file = storage_slots_file.as_deref();
+        let name = instance_name(
+            pkg.descriptor.manifest_file.project_name(),
+            storage_slots_file,
+        );
+        let result = self
+            .deploy_contract(&pkg, storage_slots_file)
+            .await
+            .map_err(|e| match e.downcast::<DeployCancelled>() {
+                Ok(cancelled) => DeployCancelled {
+                    committed: self.committed.clone(),
+                    ..cancelled
+                }
+                .into(),
+                Err(e) => e,
+            });
+        if let (Ok(contract), Some(storage_slots_file)) = (&result, storage_slots_file) {
+            info!(
+                "Deployed {} with {storage_slots_file} as 0x{}",
+                pkg.descriptor.manifest_file.project_name(),
+                contract.id
+            );
+        }
+        Some((name, result))
+    }
+
+    async fn deploy_contract(
+        &mut self,
+        pkg: &BuiltPackage,
+        storage_slots_file: Option<&str>,
+    ) -> Result<DeployedContract> {
+        let ((salt, effective_salt), salt_source) = selected_salt(
+            &self.command,
+            self.salt_strategy.as_deref(),
+            self.contract_salt_map.as_ref(),
+            &pkg.descriptor.manifest_file,
+        )?;
+        let node_url = get_node_url(&self.command.node, &pkg.descriptor.manifest_file.network)?;
+        let skipped = match self
+            .resumed_contract(pkg, storage_slots_file, effective_salt, &node_url)
+            .await?
+        {
+            Some(contract) => Some(contract),
+            None => self.unchanged_abi_contract(pkg, &node_url)?,
+        };
+        let contract = match skipped {
+            Some(contract) => contract,
+            None => {
+                deploy_instance(
+                    &self.command,
+                    &pkg.descriptor.manifest_file,
+                    pkg,
+                    storage_slots_file,
+                    effective_salt,
+                    salt_source,
+                    &mut self.context,
+                )
+                .await?
+            }
+        };
+        self.committed.push(contract.id);
+        let name = pkg.descriptor.manifest_file.project_name();
+        self.deployed.push((name.to_string(), contract.id));
+        self.node_url = Some(node_url);
+        self.deployed_salts.insert(
+            pkg.descriptor.manifest_file.project_name().to_string(),
+            salt,
+        );
+        Ok(contract)
+    }
+
+    /// With `--resume`, returns the given contract if an earlier run already deployed it with the
+    /// same contract ID, as recorded by a deployment artifact for the network, and it is still
+    /// present on-chain.
+    ///
+    /// The contract ID is derived from the bytecode root, the salt and the storage slots, so a
+    /// matching ID means the contract would be deployed unchanged.
+    async fn resumed_contract(
+        &self,
+        pkg: &BuiltPackage,
+        storage_slots_file: Option<&str>,
+        salt: Salt,
+        node_url: &str,
+    ) -> Result<Option<DeployedContract>> {
+        if !self.command.resume {
+            return Ok(None);
+        }
+        let manifest = &pkg.descriptor.manifest_file;
+        let name = manifest.project_name();
+        let (bytecode, storage_slots) = deployed_code(&self.command, pkg, storage_slots_file)?;
+        let contract_id = ContractIdentity::new(&bytecode, &storage_slots, &salt).contract_id;
+        let artifact_path = deployments_output_dir(&self.command, manifest)?
+            .join(format!("{name}-deployment-0x{contract_id}.json"));
+        let recorded = std::fs::read_to_string(artifact_path)
+            .ok()
+            .and_then(|artifact| serde_json::from_str::<DeploymentArtifact>(&artifact).ok())
+            .is_some_and(|artifact| artifact.network_endpoint == node_url);
+        if !recorded {
+            return Ok(None);
+        }
+        let client = FuelClient::new(node_url)?;
+        let contract = self
+            .context
+            .cancellation
+            .run(client.contract(&contract_id))
+            .await??;
+        if contract.is_none() {
+            println_warning(&format!(
+                "The deployment artifact of {name} records 0x{contract_id}, but it was not found at {node_url}, deploying it again"
+            ));
+            return Ok(None);
+        }
+        info!("Skipping {name}: 0x{contract_id} was already deployed to {node_url}");
+        Ok(Some(DeployedContract {
+            id: contract_id,
+            receipts: None,
+        }))
+    }
+
+    /// With `--if-abi-changed`, returns the contract recorded by the latest deployment artifact of
+    /// the given contract for the network if its ABI is identical to the one of the build.
+    ///
+    /// Otherwise, the differences between the ABIs are printed and the contract is deployed.
+    fn unchanged_abi_contract(
+        &self,
+        pkg: &BuiltPackage,
+        node_url: &str,
+    ) -> Result<Option<DeployedContract>> {
+        if !self.command.if_abi_changed {
+            return Ok(None);
+        }
+        let manifest = &pkg.descriptor.manifest_file;
+        let name = manifest.project_name();
+        let output_dir = deployments_output_dir(&self.command, manifest)?;
+        let Some(artifact) = find_latest_artifact(&output_dir, name, node_url) else {
+            info!("No deployment of {name} to {node_url} is recorded, deploying it");
+            return Ok(None);
+        };
+        let (Some(recorded_abi), ProgramABI::Fuel(abi)) = (&artifact.abi, &pkg.program_abi) else {
+            println_warning(&format!(
+                "The latest deployment artifact of {name} records no ABI to compare against, deploying it"
+            ));
+            return Ok(None);
+        };
+        let previous_id = artifact
+            .contract_id
+            .parse::<ContractId>()
+            .map_err(anyhow::Error::msg)
+            .with_context(|| format!("Invalid contract ID in the deployment artifact of {name}"))?;
+        if recorded_abi == abi {
+            info!("Skipping {name}: its ABI is unchanged since 0x{previous_id} was deployed to {node_url}");
+            return Ok(Some(DeployedContract {
+                id: previous_id,
+                receipts: None,
+            }));
+        }
+        info!("ABI of {name} changed since 0x{previous_id} was deployed:");
+        AbiDiff::between(recorded_abi, abi)?.print_summary();
+        Ok(None)
+    }
+
+    /// Writes the salts of the deployed contracts and runs the `--post-deploy-cmd`, if requested,
+    /// once all contracts were deployed.
+    ///
+    /// A failing post-deploy command is reported as a warning, as the contracts are deployed
+    /// regardless.
+    fn finish(&self) -> Result<()> {
+        if let Some(salt_file) = &self.command.write_salts {
+            write_salt_file(salt_file, &self.deployed_salts)?;
+        }
+        if let (Some(post_deploy_cmd), Some(node_url)) =
+            (&self.command.post_deploy_cmd, &self.node_url)
+        {
+            let envs = hooks::post_deploy_envs(node_url, &self.deployed);
+            if let Err(e) = hooks::run("post-deploy", post_deploy_cmd, &envs) {
+                println_warning(&format!("{e}. The deployed contracts are not affected."));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Builds the packages and gathers everything needed to deploy them, prompting for passwords
+/// upfront. Returns `None` if there is nothing to deploy.
+async fn prepare_deployment(
+    command: cmd::Deploy,
+    options: DeployOptions,
+) -> Result<Option<Deployment>> {
+    let mut command = command;
+    if command.print_json_schema {
+        let schema = schema::deployment_artifact_schema();
+        println!("{}", serde_json::to_string_pretty(&schema)?);
+        return Ok(None);
+    }
+    let DeployOptions {
+        policy,
+        salt_strategy,
+        tx_builder_hook,
+        cancellation,
+    } = options;
+    let policy = policy.unwrap_or_else(|| DeployPolicy::from(&command));
+    if salt_strategy.is_some()
+        && (command.salt.is_some()
+            || command.salt_file.is_some()
+            || command.salt_from_git
+            || command.default_salt)
+    {
+        bail!("The salt of each contract is selected by the salt strategy, `--salt`, `--salt-file`, `--salt-from-git` and `--default-salt` can't be used with one");
+    }
+    if command.unsigned {
+        println_warning("--unsigned flag is deprecated, please prefer using --default-signer. Assuming `--default-signer` is passed. This means your transaction will be signed by an account that is funded by fuel-core by default for testing purposes.");
+    }
+
+    if let Some(fork) = command.fork.clone() {
+        target_fork(&mut command, fork)?;
+    }
+
+    if command.list_deployments {
+        list::list(&command)?;
+        return Ok(None);
+    }
+
+    if command.doctor {
+        doctor::doctor(&command).await?;
+        return Ok(None);
+    }
+
+    validate_deployments_dir(&command.deployments_dir)?;
+    parse_tags(&command.tags)?;
+
+    // The temporary workspace of a `--git` package is removed once the deployment is prepared.
+    let git_workspace = match &command.git {
+        Some(repo) => {
+            if command.pkg.path.is_some() {
+                bail!("`--git` deploys the package at the root of the repository, it can't be combined with `--path`");
+            }
+            let workspace = remote::checkout_git_package(&command, repo)?;
+            // Keep the build and deployment artifacts next to the caller rather than in the
+            // temporary workspace.
+            if command.pkg.output_directory.is_none() {
+                let output_dir = default_output_directory(&std::env::current_dir()?);
+                command.pkg.output_directory = Some(output_dir.display().to_string());
+            }
+            Some(workspace)
+        }
+        None => None,
+    };
+
+    let curr_dir = if let Some(workspace) = &git_workspace {
+        workspace.path().to_path_buf()
+    } else if let Some(ref path) = command.pkg.path {
+        PathBuf::from(path)
+    } else {
+        std::env::current_dir()?
+    };
+
+    check_deployable_members(&curr_dir, command.include_predicates)?;
+
+    let build_opts = build_opts_from_cmd(&command);
+    let built_pkgs = built_pkgs(&curr_dir, &build_opts).map_err(|e| {
+        if command.frozen {
+            e.context("`--frozen` requires building the dependency graph pinned in Forc.lock as is, without network access")
+        } else {
+            e
+        }
+    })?;
+
+    if built_pkgs.is_empty() {
+        println_warning("No deployable contracts found in the current directory.");
+        return Ok(None);
+    }
+    let warnings = collect_warnings(&built_pkgs);
+    // Predicates aren't deployed, their addresses are recorded once the contracts are checked.
+    let (predicates, built_pkgs): (Vec<_>, Vec<_>) = built_pkgs.into_iter().partition(|pkg| {
+        pkg.descriptor
+            .manifest_file
+            .check_program_type(&[TreeType::Predicate])
+            .is_ok()
+    });
+    check_unique_names(
+        built_pkgs
+            .iter()
+            .chain(&predicates)
+            .map(|pkg| &pkg.descriptor.manifest_file)
+            .map(|manifest| (manifest.project_name(), manifest.dir())),
+    )?;
+
+    if command.print_storage_slots {
+        let contracts: Vec<_> = built_pkgs
+            .iter()
+            .filter(|pkg| {
+                pkg.descriptor
+                    .manifest_file
+                    .check_program_type(&[TreeType::Contract])
+                    .is_ok()
+            })
+            .map(|pkg| {
+                (
+                    pkg.descriptor.manifest_file.project_name(),
+                    &pkg.storage_slots[..],
+                )
+            })
+            .collect();
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&storage_slots_json(&contracts)?)?
+        );
+        return Ok(None);
+    }
+
+    if let Some(other_id) = command.abi_compat_check {
+        check_abi_compat(&command, &built_pkgs, other_id)?;
+        return Ok(None);
+    }
+
+    // The node is only spawned once nothing is left to exit early without deploying. It is shut
+    // down once the deployment is dropped.
+    let local_node = if command.spawn_node {
+        Some(spawn_local_node(&mut command).await?)
+    } else {
+        None
+    };
+
+    check_build_profile(&command, &built_pkgs)?;
+    check_default_signer(&command, &built_pkgs)?;
+
+    let contract_salt_map = if let Some(salt_input) = &command.salt {
+        // If we're building 1 package, we just parse the salt as a string, ie. 0x00...
+        // If we're building >1 package, we must parse the salt as a pair of strings, ie. contract_name:0x00...
+        if built_pkgs.len() > 1 {
+            let map = validate_and_parse_salts(
+                salt_input,
+                built_pkgs.iter().map(|b| &b.descriptor.manifest_file),
+            )?;
+
+            Some(map)
+        } else {
+            if salt_input.len() > 1 {
+                bail!("More than 1 salt was specified when deploying a single contract");
+            }
+
+            // OK to index into salt_input and built_pkgs_with_manifest here,
+            // since both are known to be len 1.
+
+            let salt = parse_salt(&salt_input[0])?;
+            let mut contract_salt_map = ContractSaltMap::default();
+            contract_salt_map.insert(
+                built_pkgs[0]
+                    .descriptor
+                    .manifest_file
+                    .project_name()
+                    .to_string(),
+                salt,
+            );
+            Some(contract_salt_map)
+        }
+    } else if let Some(salt_file) = &command.salt_file {
+        let salt_input = read_salt_file(salt_file)?;
+        let map = validate_and_parse_salts(
+            &salt_input,
+            built_pkgs.iter().map(|b| &b.descriptor.manifest_file),
+        )?;
+        Some(map)
+    } else if command.salt_from_git {
+        let commit = git_head_commit(&curr_dir, command.allow_dirty)?;
+        info!("Deriving salts from git commit {commit}");
+        let map = built_pkgs
+            .iter()
+            .map(|pkg| {
+                let name = pkg.descriptor.manifest_file.project_name();
+                (name.to_string(), salt_from_git_commit(&commit, name))
+            })
+            .collect();
+        Some(map)
+    } else {
+        None
+    };
+
+    let configurable_overrides = ConfigurableOverrides::parse(&command.configurables)?;
+    if !configurable_overrides.is_empty() {
+        if command.no_encoding_v1 {
+            bail!("Overriding configurables requires the new encoding, remove `--no-encoding-v1`");
+        }
+        configurable_overrides.validate(
+            built_pkgs
+                .iter()
+                .filter(|pkg| {
+                    pkg.descriptor
+                        .manifest_file
+                        .check_program_type(&[TreeType::Contract])
+                        .is_ok()
+                })
+                .map(|pkg| &pkg.program_abi),
+        )?;
+    }
+
+    if let Some(name) = &command.set_deployer_configurable {
+        if command.no_encoding_v1 {
+            bail!("Setting a configurable to the deployer's address requires the new encoding, remove `--no-encoding-v1`");
+        }
+        validate_address_configurable(
+            name,
+            built_pkgs
+                .iter()
+                .filter(|pkg| {
+                    pkg.descriptor
+                        .manifest_file
+                        .check_program_type(&[TreeType::Contract])
+                        .is_ok()
+                })
+                .map(|pkg| &pkg.program_abi),
+        )?;
+        if ConfigurableOverrides::parse(&command.configurables)?.contains(name) {
+            bail!("Configurable `{name}` can't be both overridden with `--configurable` and set to the deployer's address");
+        }
+    }
+
+    check_zeroed_configurables(&command, &built_pkgs)?;
+    check_storage_slot_instances(&command)?;
+    for storage_slots_file in &command.override_storage_slots {
+        read_storage_slots_file(storage_slots_file)?;
+    }
+
+    if command.predict_id {
+        predict_contract_ids(
+            &command,
+            &built_pkgs,
+            salt_strategy.as_deref(),
+            contract_salt_map.as_ref(),
+        )?;
+        return Ok(None);
+    }
+
+    if command.diff {
+        contract_diff::diff(
+            &command,
+            &built_pkgs,
+            salt_strategy.as_deref(),
+            contract_salt_map.as_ref(),
+        )
+        .await?;
+        return Ok(None);
+    }
+
+    if !predicates.is_empty() {
+        predicate::record_predicates(&command, &predicates)?;
+        if built_pkgs.is_empty() {
+            return Ok(None);
+        }
+    }
+
+    check_deployments_dirs_writable(&command, &built_pkgs)?;
+
+    if command.encrypt_artifact && command.bundle.is_some() {
+        bail!("`--bundle` stores the deployment artifact unencrypted, it can't be combined with `--encrypt-artifact`");
+    }
+    if command.encrypt_artifact && command.sign_artifact {
+        bail!("Signatures of encrypted deployment artifacts can't be verified, `--sign-artifact` can't be combined with `--encrypt-artifact`");
+    }
+    let artifact_password = if command.encrypt_artifact {
+        Some(prompt_artifact_password()?)
+    } else {
+        None
+    };
+
+    if let Some(hd_path) = &command.hd_path {
+        if command.default_signer || command.signing_key.is_some() {
+            bail!("`--hd-path` selects the forc-wallet key to sign with, it can't be combined with `--default-signer` or a signing key");
+        }
+        validate_hd_path(hd_path)?;
+    }
+
+    let wallet_mode = wallet_mode(&command)?;
+
+    confirm_networks(&command, &built_pkgs).await?;
+
+    if let Some(multiplier) = command.tip_multiplier {
+        if !multiplier.is_finite() || multiplier < 0.0 {
+            bail!("Invalid tip multiplier `{multiplier}` - must be a non-negative number");
+        }
+    }
+    let tip = resolve_tip(&command, &policy).await;
+    parse_input_coins(&command.input_coins)?;
+
+    cancellation.check()?;
+    if let Some(pre_deploy_cmd) = &command.pre_deploy_cmd {
+        hooks::run("pre-deploy", pre_deploy_cmd, &[])
+            .context("Aborting the deployment as the pre-deploy command failed")?;
+    }
+
+    let instances = built_pkgs
+        .iter()
+        .flat_map(|pkg| {
+            storage_slot_configs(&command)
+                .into_iter()
+                .map(|file| (pkg.clone(), file.map(str::to_string)))
+        })
+        .collect::<Vec<_>>()
+        .into_iter();
+
+    let node_info_cache = NodeInfoCache {
+        check_node_version: !command.skip_node_version_check,
+        ..Default::default()
+    };
+    let context = DeployContext {
+        policy,
+        tip,
+        wallet_mode,
+        artifact_password,
+        tx_builder_hook,
+        node_info_cache,
+        spent_inputs: SpentInputs::default(),
+        cancellation,
+    };
+    Ok(Some(Deployment {
+        command,
+        _local_node: local_node,
+        instances,
+        contract_salt_map,
+        salt_strategy,
+        context,
+        committed: Vec::new(),
+        deployed: Vec::new(),
+        node_url: None,
+        deployed_salts: ContractSaltMap::default(),
+        warnings,
+    }))
+}
+
+/// Returns how the signer of the deployments is selected, prompting for the forc-wallet password
+/// unless the default signer or a signing key is used.
+fn wallet_mode(command: &cmd::Deploy) -> Result<WalletSelectionMode> {
+    let wallet_mode = if command.default_signer || command.signing_key.is_some() {
+        WalletSelectionMode::Manual
+    } else {
+        let password = prompt_forc_wallet_password(&default_wallet_path())?;
+        WalletSelectionMode::ForcWallet(password)
+    };
+    Ok(wallet_mode)
+}
+
+/// Fails if the package at `path` is not a contract, and warns about each member of the workspace
+/// at `path` that is skipped for not being a contract.
+///
+/// With `include_predicates`, predicates are deployable as well.
+fn check_deployable_members(path: &Path, include_predicates: bool) -> Result<()> {
+    let program_type_name = |program_type: TreeType| format!("{program_type:?}").to_lowercase();
+    let is_deployable = |program_type: TreeType| {
+        program_type == TreeType::Contract
+            || (include_predicates && program_type == TreeType::Predicate)
+    };
+    match ManifestFile::from_dir(path)? {
+        ManifestFile::Package(manifest) => {
+            let program_type = manifest.program_type()?;
+            if !is_deployable(program_type) {
+                bail!(
+                    "`{}` is a {}, not a contract: only contracts can be deployed",
+                    manifest.project_name(),
+                    program_type_name(program_type)
+                );
+            }
+        }
+        workspace @ ManifestFile::Workspace(_) => {
+            for (name, manifest) in workspace.member_manifests()? {
+                let program_type = manifest.program_type()?;
+                if !is_deployable(program_type) {
+                    println_warning(&format!(
+                        "Skipping workspace member `{name}`: it is a {}, only contracts are deployed",
+                        program_type_name(program_type)
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Fails if several of the given packages, given by name and directory, share the same name, as
+/// their salts and deployment artifacts are keyed by name and would collide.
+fn check_unique_names<'a>(pkgs: impl Iterator<Item = (&'a str, &'a Path)>) -> Result<()> {
+    let mut dirs_by_name: BTreeMap<&str, Vec<&Path>> = BTreeMap::new();
+    for (name, dir) in pkgs {
+        dirs_by_name.entry(name).or_default().push(dir);
+    }
+    let duplicates: Vec<_> = dirs_by_name
+        .iter()
+        .filter(|(_, dirs)| dirs.len() > 1)
+        .map(|(name, dirs)| {
+            let dirs: Vec<_> = dirs
+                .iter()
+                .map(|dir| format!("    {}", dir.display()))
+                .collect();
+            format!("  `{name}`:\n{}", dirs.join("\n"))
+        })
+        .collect();
+    if !duplicates.is_empty() {
+        bail!(
+            "Several packages share the same name, so their salts and deployment artifacts would collide:\n{}\n\
+            Rename the packages in their Forc.toml so that each name is unique",
+            duplicates.join("\n")
+        );
+    }
+    Ok(())
+}
+
+/// Returns the storage slots of the given contracts in the format expected by
+/// `--override-storage-slots`.
+///
+/// The slots of a single contract are returned as is, while those of several contracts are keyed
+/// by contract name.
+fn storage_slots_json(contracts: &[(&str, &[StorageSlot])]) -> Result<serde_json::Value> {
+    let sorted = |storage_slots: &[StorageSlot]| {
+        let mut storage_slots = storage_slots.to_vec();
+        storage_slots.sort();
+        serde_json::to_value(storage_slots)
+    };
+    let value = match contracts {
+        [(_, storage_slots)] => sorted(storage_slots)?,
+        _ => serde_json::Value::Object(
+            contracts
+                .iter()
+                .map(|(name, storage_slots)| Ok((name.to_string(), sorted(storage_slots)?)))
+                .collect::<Result<_>>()?,
+        ),
+    };
+    Ok(value)
+}
+
+/// Warns when deploying a build that doesn't use the release profile, and refuses to deploy it to
+/// a non-local node unless `--allow-debug-deploy` is passed.
+fn check_build_profile(command: &cmd::Deploy, built_pkgs: &[Arc<BuiltPackage>]) -> Result<()> {
+    if command.build_profile == BuildProfile::RELEASE {
+        return Ok(());
+    }
+    println_warning(&format!(
+        "Deploying a build using the `{}` profile, which is unoptimized. Consider using `--build-profile release`.",
+        command.build_profile
+    ));
+    if command.allow_debug_deploy {
+        return Ok(());
+    }
+    for pkg in built_pkgs {
+        let node_url = get_node_url(&command.node, &pkg.descriptor.manifest_file.network)?;
+        if !is_local_node_url(&node_url) {
+            bail!(
+                "Refusing to deploy a `{}` build to the non-local node at {node_url}, pass `--allow-debug-deploy` to proceed anyway",
+                command.build_profile
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Warns about integer configurables that are zero in the bytecode to be deployed, as they were
+/// likely left unset, and refuses to deploy them with `--strict-configurables`.
+fn check_zeroed_configurables(
+    command: &cmd::Deploy,
+    built_pkgs: &[Arc<BuiltPackage>],
+) -> Result<()> {
+    // Configurable values are only laid out as read here with the new encoding.
+    if command.no_encoding_v1 {
+        return Ok(());
+    }
+    let mut zeroed = Vec::new();
+    for pkg in built_pkgs {
+        let manifest = &pkg.descriptor.manifest_file;
+        if manifest.check_program_type(&[TreeType::Contract]).is_err() {
+            continue;
+        }
+        let (bytecode, _) = deployed_code(command, pkg, None)?;
+        for name in zeroed_configurables(&bytecode, &pkg.program_abi)? {
+            println_warning(&format!(
+                "Configurable `{name}` of contract `{}` is zero, it may have been left unset. \
+                Set it with `--configurable {name}=<VALUE>` if that is not intended.",
+                manifest.project_name()
+            ));
+            zeroed.push(format!("{}::{name}", manifest.project_name()));
+        }
+    }
+    if command.strict_configurables && !zeroed.is_empty() {
+        bail!(
+            "Refusing to deploy with zero configurables due to `--strict-configurables`: {}",
+            zeroed.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Warns when the default signer is used against a non-local node, which doesn't fund the
+/// default signer's account, so the deployment would fail when paying the fee.
+fn check_default_signer(command: &cmd::Deploy, built_pkgs: &[Arc<BuiltPackage>]) -> Result<()> {
+    if !(command.default_signer || command.unsigned) {
+        return Ok(());
+    }
+    let mut node_urls = built_pkgs
+        .iter()
+        .map(|pkg| get_node_url(&command.node, &pkg.descriptor.manifest_file.network))
+        .collect::<Result<Vec<_>>>()?;
+    node_urls.sort();
+    node_urls.dedup();
+    for node_url in node_urls.iter().filter(|url| !is_local_node_url(url)) {
+        println_warning(&format!(
+            "`--default-signer` signs with the account pre-funded by a local fuel-core node, \
+            but {node_url} is not a local node and likely doesn't fund it. \
+            The deployment will likely fail to pay its fee, consider signing with your wallet instead."
+        ));
+    }
+    Ok(())
+}
+
+/// With `--confirm-network`, asks the operator to type the name of each non-local network the
+/// contracts are about to be deployed to, so that a production deployment can't be confirmed out
+/// of habit.
+async fn confirm_networks(command: &cmd::Deploy, built_pkgs: &[Arc<BuiltPackage>]) -> Result<()> {
+    if !command.confirm_network {
+        return Ok(());
+    }
+    let mut node_urls = built_pkgs
+        .iter()
+        .map(|pkg| get_node_url(&command.node, &pkg.descriptor.manifest_file.network))
+        .collect::<Result<Vec<_>>>()?;
+    node_urls.sort();
+    node_urls.dedup();
+    for node_url in node_urls.iter().filter(|url| !is_local_node_url(url)) {
+        if !std::io::stdin().is_terminal() {
+            bail!(
+                "`--confirm-network` requires typing the network name in an interactive terminal"
+            );
+        }
+        let network_name = Provider::connect(node_url).await?.chain_info().await?.name;
+        print!(
+            "\nYou are about to deploy to the network `{network_name}` at {node_url}.\n\
+            Type `{network_name}` to confirm: "
+        );
+        std::io::stdout().flush()?;
+        let mut answer = String::new();
+        std::io::stdin().read_line(&mut answer)?;
+        if answer.trim() != network_name {
+            bail!("Deployment to `{network_name}` was not confirmed");
+        }
+    }
+    Ok(())
+}
+
+/// Returns the commit checked out in the git repository containing `dir`.
+///
+/// Fails if the working tree has uncommitted changes, unless `allow_dirty` is set.
+fn git_head_commit(dir: &Path, allow_dirty: bool) -> Result<String> {
+    let repo = git2::Repository::discover(dir).with_context(|| {
+        format!(
+            "`--salt-from-git` requires {} to be in a git repository",
+            dir.display()
+        )
+    })?;
+    let commit = repo
+        .head()
+        .and_then(|head| head.peel_to_commit())
+        .with_context(|| format!("Failed to read the commit checked out in {}", dir.display()))?
+        .id()
+        .to_string();
+    let mut status_opts = git2::StatusOptions::new();
+    status_opts.include_untracked(true);
+    if !repo.statuses(Some(&mut status_opts))?.is_empty() {
+        if !allow_dirty {
+            bail!(
+                "The working tree at {} has uncommitted changes, so the deployment wouldn't match \
+                commit {commit}. Commit them first, or pass `--allow-dirty` to proceed anyway",
+                dir.display()
+            );
+        }
+        println_warning(&format!(
+            "The working tree has uncommitted changes, the deployment may not match commit {commit}"
+        ));
+    }
+    Ok(commit)
+}
+
+/// Derives the salt of the given contract from a git commit hash.
+fn salt_from_git_commit(commit: &str, contract_name: &str) -> Salt {
+    let digest = Hasher::default()
+        .chain(commit.as_bytes())
+        .chain(contract_name.as_bytes())
+        .digest();
+    Salt::new(*digest)
+}
+
+/// Returns the salt selected for the given contract, and the salt it is effectively deployed
+/// with once the `--salt-prefix` is applied.
+fn contract_salt(
+    command: &cmd::Deploy,
+    contract_salt_map: Option<&ContractSaltMap>,
+    manifest: &PackageManifestFile,
+) -> Result<(Salt, Salt)> {
+    let salt = select_salt(
+        contract_salt_map,
+        command.default_salt,
+        &command.node,
+        manifest,
+    )?;
+    Ok((salt, prefixed_salt(command, salt)))
+}
+
+/// Returns the salt selected for the given contract by the salt strategy if any, or by the salt
+/// flags otherwise, along with the salt it is effectively deployed with and where it is taken
+/// from.
+fn selected_salt(
+    command: &cmd::Deploy,
+    salt_strategy: Option<&dyn SaltStrategy>,
+    contract_salt_map: Option<&ContractSaltMap>,
+    manifest: &PackageManifestFile,
+) -> Result<((Salt, Salt), SaltSource)> {
+    match salt_strategy {
+        Some(salt_strategy) => {
+            let salt = salt_strategy.salt(manifest)?;
+            Ok(((salt, prefixed_salt(command, salt)), SaltSource::Strategy))
+        }
+        None => Ok((
+            contract_salt(command, contract_salt_map, manifest)?,
+            salt_source(command, contract_salt_map, manifest),
+        )),
+    }
+}
+
+/// Returns where the salt selected for the given contract by [contract_salt] is taken from.
+fn salt_source(
+    command: &cmd::Deploy,
+    contract_salt_map: Option<&ContractSaltMap>,
+    manifest: &PackageManifestFile,
+) -> SaltSource {
+    match contract_salt_map {
+        Some(map) if !map.contains_key(manifest.project_name()) => SaltSource::Default,
+        Some(_) if command.salt_file.is_some() => SaltSource::File,
+        Some(_) if command.salt_from_git => SaltSource::Git,
+        Some(_) => SaltSource::Explicit,
+        None if command.default_salt => SaltSource::Default,
+        None if manifest_network_salt(&command.node, manifest).is_some() => SaltSource::Manifest,
+        None => SaltSource::Random,
+    }
+}
+
+/// Returns the salt a contract is effectively deployed with once the `--salt-prefix`, if any, is
+/// applied to the given salt.
+fn prefixed_salt(command: &cmd::Deploy, salt: Salt) -> Salt {
+    match &command.salt_prefix {
+        Some(prefix) => {
+            let effective_salt = apply_salt_prefix(prefix, &salt);
+            info!("Salt 0x{salt} namespaced by the salt prefix to 0x{effective_salt}");
+            effective_salt
+        }
+        None => salt,
+    }
+}
+
+/// Returns the storage slot configurations each contract is deployed with: one per
+/// `--override-storage-slots` file, or the compiled storage slots if there is none.
+fn storage_slot_configs(command: &cmd::Deploy) -> Vec<Option<&str>> {
+    if command.override_storage_slots.is_empty() {
+        vec![None]
+    } else {
+        command
+            .override_storage_slots
+            .iter()
+            .map(|file| Some(file.as_str()))
+            .collect()
+    }
+}
+
+/// Returns the name of a contract deployed with the given storage slots file, which tells apart
+/// the instances of a contract deployed with several `--override-storage-slots` files.
+fn instance_name(name: &str, storage_slots_file: Option<&str>) -> String {
+    match storage_slots_file {
+        Some(file) => format!("{name} ({file})"),
+        None => name.to_string(),
+    }
+}
+
+/// Fails if several instances of each contract are deployed via `--override-storage-slots` along
+/// with the options that record or look up a single deployment per contract name: the salts
+/// written by `--write-salts`, the contracts passed to the `--post-deploy-cmd` and the deployment
+/// compared against by `--if-abi-changed`.
+fn check_storage_slot_instances(command: &cmd::Deploy) -> Result<()> {
+    if command.override_storage_slots.len() < 2 {
+        return Ok(());
+    }
+    let conflicting = [
+        (command.write_salts.is_some(), "--write-salts"),
+        (command.post_deploy_cmd.is_some(), "--post-deploy-cmd"),
+        (command.if_abi_changed, "--if-abi-changed"),
+    ];
+    if let Some((_, flag)) = conflicting.iter().find(|(set, _)| *set) {
+        bail!(
+            "`{flag}` handles one deployment per contract, it can't be combined with several `--override-storage-slots` files"
+        );
+    }
+    Ok(())
+}
+
+/// Reads the sorted storage slots of an `--override-storage-slots` file.
+fn read_storage_slots_file(storage_slots_file: &str) -> Result<Vec<StorageSlot>> {
+    let read = || -> Result<Vec<StorageSlot>> {
+        let storage_slots = std::fs::read_to_string(storage_slots_file)?;
+        let mut storage_slots: Vec<StorageSlot> = serde_json::from_str(&storage_slots)?;
+        storage_slots.sort();
+        ensure_unique_storage_slot_keys(&storage_slots)?;
+        Ok(storage_slots)
+    };
+    read().with_context(|| format!("Invalid storage slots file {storage_slots_file}"))
+}
+
+/// Returns the bytecode and the sorted storage slots the given contract is deployed with, once
+/// the configurable overrides are applied and the storage slots are replaced by those of the
+/// given `--override-storage-slots` file, if any.
+fn deployed_code(
+    command: &cmd::Deploy,
+    compiled: &BuiltPackage,
+    storage_slots_file: Option<&str>,
+) -> Result<(Vec<u8>, Vec<StorageSlot>)> {
+    let mut bytecode = compiled.bytecode.bytes.clone();
+    ConfigurableOverrides::parse(&command.configurables)?
+        .apply(&mut bytecode, &compiled.program_abi)?;
+
+    let storage_slots = match storage_slots_file {
+        Some(storage_slots_file) => read_storage_slots_file(storage_slots_file)?,
+        None => {
+            let mut storage_slots = compiled.storage_slots.clone();
+            storage_slots.sort();
+            ensure_unique_storage_slot_keys(&storage_slots)?;
+            storage_slots
+        }
+    };
+    Ok((bytecode, storage_slots))
+}
+
+/// Prints the number and size of the storage slots a contract is deployed with, and whether they
+/// were compiled or replaced by the given `--override-storage-slots` file.
+fn print_storage_slots_summary(
+    compiled: &BuiltPackage,
+    storage_slots: &[StorageSlot],
+    storage_slots_file: Option<&str>,
+) {
+    let size = storage_slots.len() * 2 * Bytes32::LEN;
+    match storage_slots_file {
+        Some(override_file) => info!(
+            "Storage slots: {} ({size} bytes) from {override_file}, replacing the {} compiled slots",
+            storage_slots.len(),
+            compiled.storage_slots.len()
+        ),
+        None => info!(
+            "Storage slots: {} ({size} bytes) compiled",
+            storage_slots.len()
+        ),
+    }
+}
+
+/// Computes the ID of the contract deployed with the given bytecode, salt and storage slots.
+///
+/// The storage slots are sorted before computing the state root, as they are when deploying.
+pub fn compute_contract_id(
+    bytecode: &[u8],
+    salt: &Salt,
+    storage_slots: &[StorageSlot],
+) -> ContractId {
+    let mut storage_slots = storage_slots.to_vec();
+    storage_slots.sort();
+    ContractIdentity::new(bytecode, &storage_slots, salt).contract_id
+}
+
+/// The roots a contract ID is derived from, along with the ID itself.
+#[derive(Debug, PartialEq, Eq)]
+struct ContractIdentity {
+    root: Bytes32,
+    state_root: Bytes32,
+    contract_id: ContractId,
+}
+
+impl ContractIdentity {
+    fn new(bytecode: &[u8], storage_slots: &[StorageSlot], salt: &Salt) -> Self {
+        let contract = Contract::from(bytecode);
+        let root = contract.root();
+        let state_root = Contract::initial_state_root(storage_slots.iter());
+        let contract_id = contract.id(salt, &root, &state_root);
+        Self {
+            root,
+            state_root,
+            contract_id,
+        }
+    }
+}
+
+/// Returns the salt following the given one, taken as a 256-bit big-endian integer.
+fn next_salt(salt: &Salt) -> Salt {
+    let mut bytes = **salt;
+    for byte in bytes.iter_mut().rev() {
+        let (incremented, overflowed) = byte.overflowing_add(1);
+        *byte = incremented;
+        if !overflowed {
+            break;
+        }
+    }
+    Salt::new(bytes)
+}
+
+/// With `--salt-auto-bump`, increments the given salt until the ID the contract would be deployed
+/// with is not taken on-chain, returning the salt to deploy with.
+async fn bump_salt_until_free(
+    client: &FuelClient,
+    bytecode: &[u8],
+    storage_slots: &[StorageSlot],
+    mut salt: Salt,
+) -> Result<Salt> {
+    for _ in 0..MAX_SALT_BUMPS {
+        let contract_id = ContractIdentity::new(bytecode, storage_slots, &salt).contract_id;
+        if client.contract(&contract_id).await?.is_none() {
+            return Ok(salt);
+        }
+        let next = next_salt(&salt);
+        info!("Contract 0x{contract_id} already exists, bumping the salt 0x{salt} to 0x{next}");
+        salt = next;
+    }
+    bail!(
+        "Found no free contract ID after bumping the salt {MAX_SALT_BUMPS} times, pass a different salt"
+    )
+}
+
+/// Fails if the given contract would be deployed with a random salt, whether for lack of a salt
+/// or as configured in its manifest, as its ID can't be predicted.
+fn check_predictable_salt(
+    command: &cmd::Deploy,
+    contract_salt_map: Option<&ContractSaltMap>,
+    manifest: &PackageManifestFile,
+) -> Result<()> {
+    let random = contract_salt_map.is_none()
+        && !command.default_salt
+        && matches!(
+            targeted_network_salt(&command.node, manifest),
+            None | Some(NetworkSalt::Random)
+        );
+    if random {
+        bail!(
+            "Cannot predict the ID of contract `{}`, which would be deployed with a random salt. \
+            Pass a salt via `--salt`, `--salt-file`, `--salt-from-git` or `--default-salt`",
+            manifest.project_name()
+        );
+    }
+    Ok(())
+}
+
+/// Prints the IDs the given contracts would be deployed with, without connecting to a node.
+fn predict_contract_ids(
+    command: &cmd::Deploy,
+    built_pkgs: &[Arc<BuiltPackage>],
+    salt_strategy: Option<&dyn SaltStrategy>,
+    contract_salt_map: Option<&ContractSaltMap>,
+) -> Result<()> {
+    for pkg in built_pkgs {
+        let manifest = &pkg.descriptor.manifest_file;
+        if manifest.check_program_type(&[TreeType::Contract]).is_err() {
+            continue;
+        }
+        let name = manifest.project_name();
+        // The salts selected by a salt strategy are up to it.
+        if salt_strategy.is_none() {
+            check_predictable_salt(command, contract_salt_map, manifest)?;
+        }
+        let ((_, salt), _) = selected_salt(command, salt_strategy, contract_salt_map, manifest)?;
+        for storage_slots_file in storage_slot_configs(command) {
+            let (bytecode, storage_slots) = deployed_code(command, pkg, storage_slots_file)?;
+            let ContractIdentity {
+                root,
+                state_root,
+                contract_id,
+            } = ContractIdentity::new(&bytecode, &storage_slots, &salt);
+            if command.json {
+                let mut prediction = serde_json::json!({
+                    "contract": name,
+                    "contract_id": format!("0x{contract_id}"),
+                    "salt": format!("0x{salt}"),
+                    "bytecode_root": format!("0x{root}"),
+                    "state_root": format!("0x{state_root}"),
+                });
+                if let Some(storage_slots_file) = storage_slots_file {
+                    prediction["storage_slots_file"] = serde_json::json!(storage_slots_file);
+                }
+                println!("{prediction}");
+            } else {
+                info!("\nContract {}", instance_name(name, storage_slots_file));
+                info!("Salt: 0x{salt}");
+                info!("Bytecode root: 0x{root}");
+                info!("State root: 0x{state_root}");
+                info!("Contract ID: 0x{contract_id}");
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the built-in salt strategy selected by the salt flags.
+///
+/// In order of precedence, uses:
+/// 1. the salt provided via `--salt` or `--salt-file`, defaulting to the default salt for
+///    contracts without one,
+/// 2. the default salt if `--default-salt` is passed,
+/// 3. the salt configured in the manifest's `[network]` table, if the node is not overridden
+///    via `--node-url`, `--target` or `--testnet`,
+/// 4. a random salt.
+fn cli_salt_strategy<'a>(
+    contract_salt_map: Option<&ContractSaltMap>,
+    default_salt: bool,
+    node_target: &'a NodeTarget,
+) -> Result<Box<dyn SaltStrategy + 'a>> {
+    let strategy: Box<dyn SaltStrategy + 'a> = match (contract_salt_map, default_salt) {
+        (Some(map), false) => Box::new(ExplicitSalts(map.clone())),
+        (None, true) => Box::new(DefaultSalt),
+        (None, false) => Box::new(ManifestSalt { node_target }),
+        (Some(_), true) => {
+            bail!("Both `--salt` and `--default-salt` were specified: must choose one")
+        }
+    };
+    Ok(strategy)
+}
+
+/// Selects the salt to deploy the given contract with, as described in [cli_salt_strategy].
+fn select_salt(
+    contract_salt_map: Option<&ContractSaltMap>,
+    default_salt: bool,
+    node_target: &NodeTarget,
+    manifest: &PackageManifestFile,
+) -> Result<Salt> {
+    cli_salt_strategy(contract_salt_map, default_salt, node_target)?.salt(manifest)
+}
+
+/// Mixes the given prefix into the salt by hashing them together, namespacing the resulting
+/// contract IDs to the prefix.
+fn apply_salt_prefix(prefix: &Bytes32, salt: &Salt) -> Salt {
+    let digest = Hasher::default().chain(prefix).chain(salt).digest();
+    Salt::new(*digest)
+}
+
+/// Returns the salt configured in the manifest's `[network]` table, if the deployment targets
+/// that network.
+fn manifest_network_salt(node_target: &NodeTarget, manifest: &PackageManifestFile) -> Option<Salt> {
+    match targeted_network_salt(node_target, manifest)? {
+        NetworkSalt::Default => Some(Salt::default()),
+        NetworkSalt::Random => Some(rand::random()),
+        NetworkSalt::Explicit(salt) => Some(salt.0),
+    }
+}
+
+/// Returns the salt configuration of the manifest's `[network]` table, if the deployment targets
+/// that network.
+fn targeted_network_salt<'a>(
+    node_target: &NodeTarget,
+    manifest: &'a PackageManifestFile,
+) -> Option<&'a NetworkSalt> {
+    if node_target.node_url.is_some() || node_target.target.is_some() || node_target.testnet {
+        return None;
+    }
+    manifest.network.as_ref()?.salt.as_ref()
+}
+
+/// Reads a TOML file mapping contract names to salts into `<CONTRACT_NAME>:<SALT>` salt inputs.
+fn read_salt_file(path: &Path) -> Result<Vec<String>> {
+    let salt_file = std::fs::read_to_string(path)?;
+    let salts: BTreeMap<String, String> = toml::from_str(&salt_file)
+        .with_context(|| format!("Failed to parse salt file {path:?}"))?;
+    Ok(salts
+        .into_iter()
+        .map(|(contract_name, salt)| format!("{contract_name}:{salt}"))
+        .collect())
+}
+
+/// Writes the salt of each contract to a TOML file that can be passed back via `--salt-file`.
+fn write_salt_file(path: &Path, salts: &ContractSaltMap) -> Result<()> {
+    let salts: BTreeMap<_, _> = salts
+        .iter()
+        .map(|(contract_name, salt)| (contract_name, format!("0x{salt}")))
+        .collect();
+    std::fs::write(path, toml::to_string(&salts)?)?;
+    info!("Salts written to {}", path.display());
+    Ok(())
+}
+
+/// Prompts for the password used to encrypt deployment artifacts.
+fn prompt_artifact_password() -> Result<String> {
+    let password = rpassword::prompt_password(
+        "Please provide a password to encrypt the deployment artifacts: ",
+    )?;
+    let confirmation = rpassword::prompt_password("Please confirm the password: ")?;
+    if password != confirmation {
+        bail!("Passwords do not match");
+    }
+    Ok(password)
+}
+
+/// Spawns a local node and points the deploy command at it, signing with the default signer.
+async fn spawn_local_node(command: &mut cmd::Deploy) -> Result<LocalNode> {
+    let node_target = &command.node;
+    if node_target.node_url.is_some() || node_target.target.is_some() || node_target.testnet {
+        bail!("`--spawn-node` cannot be combined with `--testnet`, `--target`, or `--node-url`");
+    }
+    let node = LocalNode::spawn().await?;
+    info!("Spawned local node at {}", node.url());
+    command.node.node_url = Some(node.url().to_string());
+    command.default_signer = true;
+    Ok(node)
+}
+
+/// Returns the creation time of a deployment artifact, in seconds since the Unix epoch: the
+/// `SOURCE_DATE_EPOCH` if set, as for reproducible builds, or the current time otherwise.
+fn artifact_timestamp() -> Result<u64> {
+    match std::env::var("SOURCE_DATE_EPOCH") {
+        Ok(source_date_epoch) => parse_source_date_epoch(&source_date_epoch),
+        Err(_) => Ok(std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)?
+            .as_secs()),
+    }
+}
+
+/// Parses the value of `SOURCE_DATE_EPOCH`, which must be a number of seconds since the Unix
+/// epoch.
+fn parse_source_date_epoch(source_date_epoch: &str) -> Result<u64> {
+    source_date_epoch.trim().parse().map_err(|_| {
+        anyhow::anyhow!(
+            "Invalid SOURCE_DATE_EPOCH `{source_date_epoch}` - must be a number of seconds since the Unix epoch"
+        )
+    })
+}
+
+/// Parses the `--tag` inputs of the form `<KEY>=<VALUE>`.
+fn parse_tags(inputs: &[String]) -> Result<BTreeMap<String, String>> {
+    let mut tags = BTreeMap::new();
+    for input in inputs {
+        let (key, value) = input.split_once('=').ok_or_else(|| {
+            anyhow::anyhow!("Invalid tag `{input}` - must be in the form <KEY>=<VALUE>")
+        })?;
+        if key.is_empty() {
+            bail!("Invalid tag `{input}` - the key must not be empty");
+        }
+        if tags.insert(key.to_string(), value.to_string()).is_some() {
+            bail!("Tag `{key}` was given more than once");
+        }
+    }
+    Ok(tags)
+}
+
+/// Points the deployment at the node running the `--fork`, so that it is rehearsed against the
+/// forked state rather than deployed to the forked network itself.
+fn target_fork(command: &mut cmd::Deploy, fork: String) -> Result<()> {
+    let node_target = &command.node;
+    if node_target.node_url.is_some() || node_target.target.is_some() || node_target.testnet {
+        bail!("`--fork` cannot be combined with `--testnet`, `--target`, or `--node-url`");
+    }
+    println_warning(&format!(
+        "Rehearsing the deployment against the fork at {fork}. Nothing is deployed to the forked network, \
+        and deployment artifacts are written to `{REHEARSALS_DIR}` rather than `{}`.",
+        command.deployments_dir
+    ));
+    command.node.node_url = Some(fork);
+    Ok(())
+}
+
+/// Ensures that no two of the given storage slots, sorted by key, initialize the same key.
+///
+/// Duplicate keys would otherwise silently resolve to one of the values, yielding a state root
+/// that doesn't match the one expected from the storage slots file.
+fn ensure_unique_storage_slot_keys(sorted_storage_slots: &[StorageSlot]) -> Result<()> {
+    if let Some(pair) = sorted_storage_slots
+        .windows(2)
+        .find(|pair| pair[0].key() == pair[1].key())
+    {
+        bail!(
+            "Storage slot with key 0x{} is initialized more than once: 0x{} and 0x{}",
+            pair[0].key(),
+            pair[0].value(),
+            pair[1].value()
+        );
+    }
+    Ok(())
+}
+
+/// Returns the path of a plain-text deployment artifact in `output_dir` that was deployed to the
+/// given network endpoint on a chain with a different genesis block, if any.
+///
+/// Artifacts that don't record a genesis block are ignored.
+fn find_artifact_from_reset_network(
+    output_dir: &Path,
+    network_endpoint: &str,
+    genesis_block_id: &str,
+) -> Option<PathBuf> {
+    std::fs::read_dir(output_dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .find(|path| {
+            std::fs::read_to_string(path)
+                .ok()
+                .and_then(|artifact| serde_json::from_str::<DeploymentArtifact>(&artifact).ok())
+                .is_some_and(|artifact| {
+                    artifact.network_endpoint == network_endpoint
+                        && artifact
+                            .genesis_block_id
+                            .is_some_and(|id| id != genesis_block_id)
+                })
+        })
+}
+
+/// Returns the latest plain-text deployment artifact of the given contract on the given network.
+fn find_latest_artifact(
+    output_dir: &Path,
+    pkg_name: &str,
+    network_endpoint: &str,
+) -> Option<DeploymentArtifact> {
+    let artifact_prefix = format!("{pkg_name}-deployment-0x");
+    std::fs::read_dir(output_dir)
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.extension().is_some_and(|ext| ext == "json")
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with(&artifact_prefix))
+        })
+        .filter_map(|path| {
+            let artifact = std::fs::read_to_string(path).ok()?;
+            serde_json::from_str::<DeploymentArtifact>(&artifact).ok()
+        })
+        .filter(|artifact| artifact.network_endpoint == network_endpoint)
+        .max_by_key(|artifact| artifact.deployed_block_height)
+}
+
+/// Checks the given consensus parameters against the snapshot at `snapshot_path`, warning if they
+/// differ. If there is no snapshot yet, one is taken from the given parameters.
+///
+/// Transactions are always built against the node's live parameters, as the node rejects any
+/// others, so a mismatch means fees and limits may differ from the snapshotted deployment.
+fn check_consensus_parameters_snapshot(
+    snapshot_path: &Path,
+    consensus_parameters: &ConsensusParameters,
+    node_url: &str,
+) -> Result<()> {
+    if !snapshot_path.exists() {
+        let snapshot = serde_json::to_string_pretty(consensus_parameters)?;
+        std::fs::write(snapshot_path, snapshot)?;
+        info!(
+            "Consensus parameters snapshot written to {}",
+            snapshot_path.display()
+        );
+        return Ok(());
+    }
+
+    let snapshot = std::fs::read_to_string(snapshot_path)?;
+    let snapshot: ConsensusParameters = serde_json::from_str(&snapshot).with_context(|| {
+        format!("Failed to parse consensus parameters snapshot {snapshot_path:?}")
+    })?;
+    if &snapshot != consensus_parameters {
+        println_warning(&format!(
+            "The consensus parameters of the node at {node_url} no longer match the snapshot at {}. \
+            Fees and limits may differ from earlier deployments.",
+            snapshot_path.display()
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the directory the deployment artifacts of the given package are written to.
+fn deployments_output_dir(
+    command: &cmd::Deploy,
+    manifest: &PackageManifestFile,
+) -> Result<PathBuf> {
+    Ok(command
+        .pkg
+        .output_directory
+        .as_ref()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| default_output_directory(manifest.dir()))
+        .join(match command.fork {
+            Some(_) => Path::new(REHEARSALS_DIR),
+            None => validate_deployments_dir(&command.deployments_dir)?,
+        }))
+}
+
+/// Ensures that the deployment artifacts of the given contracts can be written, so that a
+/// permission problem is reported before deploying rather than after the contracts are deployed.
+fn check_deployments_dirs_writable(
+    command: &cmd::Deploy,
+    built_pkgs: &[Arc<BuiltPackage>],
+) -> Result<()> {
+    for pkg in built_pkgs {
+        let manifest = &pkg.descriptor.manifest_file;
+        if manifest.check_program_type(&[TreeType::Contract]).is_err() {
+            continue;
+        }
+        let output_dir = deployments_output_dir(command, manifest)?;
+        let writable = std::fs::create_dir_all(&output_dir)
+            .and_then(|_| tempfile::tempfile_in(&output_dir))
+            .map(|_| ());
+        match writable {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => bail!(
+                "The deployment artifacts of {} can't be written to {}: permission denied. \
+                Pass `--output-directory` to write them to a writable directory",
+                manifest.project_name(),
+                output_dir.display()
+            ),
+            Err(e) => {
+                return Err(e).with_context(|| {
+                    format!(
+                        "Failed to prepare the deployments directory {}",
+                        output_dir.display()
+                    )
+                })
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Returns the path of the JSON ABI that the build wrote for the given package.
+fn build_abi_path(command: &cmd::Deploy, manifest: &PackageManifestFile) -> PathBuf {
+    command
+        .pkg
+        .output_directory
+        .as_ref()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| default_output_directory(manifest.dir()).join(&command.build_profile))
+        .join(format!("{}-abi.json", manifest.project_name()))
+}
+
+/// Returns the ABI recorded in the plain-text deployment artifact of the given contract, searching
+/// the deployment artifacts of the given packages.
+fn find_recorded_abi(
+    command: &cmd::Deploy,
+    built_pkgs: &[Arc<BuiltPackage>],
+    contract_id: ContractId,
+) -> Result<Option<program_abi::ProgramABI>> {
+    let artifact_suffix = format!("-deployment-0x{contract_id}.json");
+    for pkg in built_pkgs {
+        let output_dir = deployments_output_dir(command, &pkg.descriptor.manifest_file)?;
+        let Ok(entries) = std::fs::read_dir(output_dir) else {
+            continue;
+        };
+        for entry in entries {
+            let path = entry?.path();
+            if path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.ends_with(&artifact_suffix))
+            {
+                let artifact: DeploymentArtifact =
+                    serde_json::from_str(&std::fs::read_to_string(&path)?)
+                        .with_context(|| format!("Failed to parse deployment artifact {path:?}"))?;
+                if artifact.abi.is_some() {
+                    return Ok(artifact.abi);
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Compares the ABI of each contract against the one recorded when the given contract was
+/// deployed, printing the added, removed and changed functions.
+///
+/// Fails if any contract is not ABI compatible with the given one.
+fn check_abi_compat(
+    command: &cmd::Deploy,
+    built_pkgs: &[Arc<BuiltPackage>],
+    other_id: ContractId,
+) -> Result<()> {
+    let other_abi = find_recorded_abi(command, built_pkgs, other_id)?.ok_or_else(|| {
+        anyhow::anyhow!(
+            "No ABI found for contract 0x{other_id}: ABIs are not published on-chain, so only \
+            contracts whose deployment artifact is in the deployments directory can be compared"
+        )
+    })?;
+
+    let mut diffs = BTreeMap::new();
+    for pkg in built_pkgs {
+        if let ProgramABI::Fuel(abi) = &pkg.program_abi {
+            if pkg
+                .descriptor
+                .manifest_file
+                .check_program_type(&[TreeType::Contract])
+                .is_ok()
+            {
+                let name = pkg.descriptor.manifest_file.project_name().to_string();
+                diffs.insert(name, AbiDiff::between(&other_abi, abi)?);
+            }
+        }
+    }
+
+    if command.json {
+        println!("{}", serde_json::to_string_pretty(&diffs)?);
+    } else {
+        for (name, diff) in &diffs {
+            info!("ABI of {name} compared to contract 0x{other_id}:");
+            diff.print_summary();
+        }
+    }
+
+    let incompatible: Vec<_> = diffs
+        .iter()
+        .filter(|(_, diff)| !diff.is_compatible())
+        .map(|(name, _)| name.as_str())
+        .collect();
+    if !incompatible.is_empty() {
+        bail!(
+            "{} not ABI compatible with contract 0x{other_id}",
+            incompatible.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// Ensures that the given deployments directory is a relative path that stays within the output
+/// directory.
+fn validate_deployments_dir(deployments_dir: &str) -> Result<&Path> {
+    let path = Path::new(deployments_dir);
+    let is_within_output_dir = path
+        .components()
+        .all(|component| matches!(component, Component::Normal(_) | Component::CurDir))
+        && path
+            .components()
+            .any(|component| matches!(component, Component::Normal(_)));
+    if !is_within_output_dir {
+        bail!(
+            "Invalid deployments directory `{deployments_dir}` - must be a relative path within the output directory"
+        );
+    }
+    Ok(path)
+}
+
+/// The details of a node that are fetched once and reused for every contract deployed to it.
+#[derive(Clone)]
+struct NodeInfo {
+    provider: Provider,
+    consensus_parameters: ConsensusParameters,
+    genesis_block_id: Option<String>,
+    /// The maximum time to wait for a submitted deployment to be committed, extended if the
+    /// node produces blocks too slowly for the policy's timeout.
+    submit_timeout: Duration,
+}
+
+/// Caches the [NodeInfo] of each node deployed to, keyed by node URL, so that deploying a
+/// workspace doesn't query the same node once per member.
+///
+/// The cache only lives for the duration of a single deployment.
+#[derive(Default)]
+pub(crate) struct NodeInfoCache {
+    nodes: HashMap<String, NodeInfo>,
+    /// Whether to warn about nodes whose version differs from the one the client is built
+    /// against.
+    check_node_version: bool,
+}
+
+impl NodeInfoCache {
+    /// Returns the details of the node at `node_url`, fetching them on first use.
+    ///
+    /// Fails if the details can't be fetched within the policy's connect timeout. The block
+    /// interval the submit timeout is extended for is then estimated within a timeout of its own,
+    /// keeping the policy's submit timeout if it can't be.
+    async fn get(
+        &mut self,
+        node_url: &str,
+        client: &FuelClient,
+        policy: &DeployPolicy,
+    ) -> Result<NodeInfo> {
+        if let Some(node_info) = self.nodes.get(node_url) {
+            return Ok(node_info.clone());
+        }
+        let timeout = policy.connect_timeout;
+        let (mut node_info, latest_block) = tokio::time::timeout(
+            timeout,
+            Self::fetch(node_url, client, policy.submit_timeout),
+        )
+        .await
+        .map_err(|_| {
+            anyhow::anyhow!(
+                "Could not reach node at {node_url} within {}ms",
+                timeout.as_millis()
+            )
+        })??;
+        // Local nodes produce blocks on demand, so the time between their blocks says nothing
+        // about how soon a submission is committed.
+        if !is_local_node_url(node_url) {
+            let block_interval =
+                tokio::time::timeout(timeout, block_interval(client, &latest_block)).await;
+            if let Ok(Some(block_interval)) = block_interval {
+                node_info.submit_timeout =
+                    extend_submit_timeout(node_url, node_info.submit_timeout, block_interval);
+            }
+        }
+        if self.check_node_version {
+            check_node_version(node_url, client).await;
+        }
+        self.nodes.insert(node_url.to_string(), node_info.clone());
+        Ok(node_info)
+    }
+
+    /// Fetches the details of the node at `node_url`, along with its latest block.
+    async fn fetch(
+        node_url: &str,
+        client: &FuelClient,
+        submit_timeout: Duration,
+    ) -> Result<(NodeInfo, fuel_core_client::client::types::Block)> {
+        let provider = Provider::connect(node_url).await?;
+        let chain_info = client.chain_info().await?;
+        let genesis_block_id = client
+            .block_by_height(0u32.into())
+            .await?
+            .map(|block| format!("0x{}", block.id));
+        let node_info = NodeInfo {
+            provider,
+            consensus_parameters: chain_info.consensus_parameters,
+            genesis_block_id,
+            submit_timeout,
+        };
+        Ok((node_info, chain_info.latest_block))
+    }
+}
+
+/// Estimates the interval at which the node produces blocks, from the timestamps of its latest
+/// blocks. Returns `None` if the chain is too short or the blocks can't be fetched.
+async fn block_interval(
+    client: &FuelClient,
+    latest_block: &fuel_core_client::client::types::Block,
+) -> Option<Duration> {
+    // The genesis block is left out, as its timestamp may predate the start of the chain.
+    let earlier_height = latest_block
+        .header
+        .height
+        .checked_sub(BLOCK_INTERVAL_SAMPLE_BLOCKS)
+        .filter(|height| *height > 0)?;
+    let earlier_block = client.block_by_height(earlier_height.into()).await.ok()??;
+    let elapsed = latest_block
+        .header
+        .time
+        .0
+        .checked_sub(earlier_block.header.time.0)?;
+    Some(Duration::from_secs(elapsed) / BLOCK_INTERVAL_SAMPLE_BLOCKS)
+}
+
+/// Returns the time to wait for a submission to be committed by a node producing a block every
+/// `block_interval`: the given timeout, extended with a warning to [MIN_SUBMIT_TIMEOUT_BLOCKS]
+/// block intervals if it is shorter, as it would otherwise likely expire before the next block.
+fn extend_submit_timeout(
+    node_url: &str,
+    submit_timeout: Duration,
+    block_interval: Duration,
+) -> Duration {
+    let min_timeout = block_interval * MIN_SUBMIT_TIMEOUT_BLOCKS;
+    if submit_timeout >= min_timeout {
+        return submit_timeout;
+    }
+    println_warning(&format!(
+        "The submit timeout of {}ms is shorter than {MIN_SUBMIT_TIMEOUT_BLOCKS} blocks of the network at {node_url}, \
+        which produces a block every {}ms. Extending the timeout to {}ms.",
+        submit_timeout.as_millis(),
+        block_interval.as_millis(),
+        min_timeout.as_millis()
+    ));
+    min_timeout
+}
+
+/// Warns if the version of the node at `node_url` differs from the version of fuel-core the
+/// client is built against, as the node may then encode transactions or enforce consensus
+/// parameters differently, making submissions fail in unexpected ways.
+///
+/// The check is a diagnostic only: failing to query the version is also just warned about.
+async fn check_node_version(node_url: &str, client: &FuelClient) {
+    match client.node_info().await {
+        Ok(node_info) if !is_supported_node_version(&node_info.node_version) => {
+            println_warning(&format!(
+                "The node at {node_url} runs fuel-core {}, while forc-client is built against fuel-core \
+                {SUPPORTED_FUEL_CORE_VERSION}. Submissions may fail if their node APIs differ.",
+                node_info.node_version
+            ))
+        }
+        Ok(_) => {}
+        Err(e) => println_warning(&format!(
+            "Could not check the version of the node at {node_url}: {e}"
+        )),
+    }
+}
+
+/// Whether the given fuel-core version is API-compatible with [SUPPORTED_FUEL_CORE_VERSION],
+/// ie. has the same major and minor versions, as minor versions of fuel-core 0.x are breaking.
+fn is_supported_node_version(node_version: &str) -> bool {
+    let major_minor = |version: &str| {
+        let mut components = version.trim_start_matches('v').split('.');
+        (components.next(), components.next())
+    };
+    major_minor(node_version) == major_minor(SUPPORTED_FUEL_CORE_VERSION)
+}
+
+/// The coins and messages spent by the deployments submitted so far.
+///
+/// Deploying a workspace from a single account submits transactions in quick succession, and the
+/// node may still report the inputs of the previous transaction as spendable. Funding each
+/// transaction from inputs that weren't spent yet keeps it from being rejected for spending them
+/// twice.
+#[derive(Default)]
+pub(crate) struct SpentInputs(Vec<CoinTypeId>);
+
+impl SpentInputs {
+    /// Records the inputs of the given submitted transaction as spent.
+    fn record(&mut self, tx: &Transaction) {
+        let Transaction::Create(create) = tx else {
+            return;
+        };
+        for input in fuel_tx::field::Inputs::inputs(create) {
+            if let Some(utxo_id) = input.utxo_id() {
+                self.0.push(CoinTypeId::UtxoId(*utxo_id));
+            } else if let Some(nonce) = input.nonce() {
+                self.0.push(CoinTypeId::Nonce(*nonce));
+            }
+        }
+    }
+}
+
+/// Adds the inputs covering the fee of the deployment transaction, paid by `payer`, along with
+/// the change output.
+///
+/// Once earlier deployments were submitted, the fee is covered by inputs they didn't spend: the
+/// fee is estimated on a copy of the transaction before selecting them. The coins passed via
+/// `--input-coin`, if any, are used instead of selecting inputs automatically.
+async fn fund_deployment(
+    payer: &WalletUnlocked,
+    tb: &mut CreateTransactionBuilder,
+    input_coins: &[UtxoId],
+    spent_inputs: &SpentInputs,
+    provider: &Provider,
+    consensus_parameters: &ConsensusParameters,
+) -> Result<()> {
+    if !input_coins.is_empty() {
+        return fund_from_input_coins(
+            payer,
+            tb,
+            input_coins,
+            spent_inputs,
+            provider,
+            consensus_parameters,
+        )
+        .await;
+    }
+    if !spent_inputs.0.is_empty() {
+        let mut estimate_tb = tb.clone();
+        payer.adjust_for_fee(&mut estimate_tb, 0).await?;
+        let estimate_tx = Transaction::from(estimate_tb.build(provider.clone()).await?);
+        let fee = estimate_fee(&estimate_tx, provider, consensus_parameters).await?;
+        let inputs = payer
+            .get_asset_inputs_for_amount(
+                *consensus_parameters.base_asset_id(),
+                fee,
+                Some(spent_inputs.0.clone()),
+            )
+            .await?;
+        tb.inputs_mut().extend(inputs);
+    }
+    payer.adjust_for_fee(tb, 0).await?;
+    Ok(())
+}
+
+/// Adds the given coins of `payer` that weren't spent by earlier deployments as the inputs of
+/// the deployment transaction, along with the change output, checking that they cover its fee.
+async fn fund_from_input_coins(
+    payer: &WalletUnlocked,
+    tb: &mut CreateTransactionBuilder,
+    input_coins: &[UtxoId],
+    spent_inputs: &SpentInputs,
+    provider: &Provider,
+    consensus_parameters: &ConsensusParameters,
+) -> Result<()> {
+    let base_asset_id = *consensus_parameters.base_asset_id();
+    let payer_address = Address::from(payer.address());
+    let spendable = provider.get_coins(payer.address(), base_asset_id).await?;
+    let mut coins = Vec::new();
+    for utxo_id in input_coins {
+        if spent_inputs.0.contains(&CoinTypeId::UtxoId(*utxo_id)) {
+            continue;
+        }
+        match spendable.iter().find(|coin| coin.utxo_id == *utxo_id) {
+            Some(coin) => coins.push(coin.clone()),
+            None => bail!(
+                "Input coin {utxo_id:#x} is not a spendable base asset coin of the fee payer 0x{payer_address}"
+            ),
+        }
+    }
+    if coins.is_empty() {
+        bail!(
+            "The input coins were all spent by earlier deployments, pass more via `--input-coin`"
+        );
+    }
+
+    let available: u64 = coins.iter().map(|coin| coin.amount).sum();
+    tb.inputs_mut().extend(
+        coins
+            .into_iter()
+            .map(|coin| fuels_core::types::input::Input::resource_signed(CoinType::Coin(coin))),
+    );
+    tb.outputs_mut()
+        .push(fuel_tx::Output::change(payer_address, 0, base_asset_id));
+
+    let estimate_tx = Transaction::from(tb.clone().build(provider.clone()).await?);
+    let fee = estimate_fee(&estimate_tx, provider, consensus_parameters).await?;
+    if available < fee {
+        bail!(
+            "The input coins hold {available} of the base asset, which doesn't cover the estimated fee of {fee}"
+        );
+    }
+    Ok(())
+}
+
+/// Parses the UTXO IDs of the coins passed via `--input-coin`.
+fn parse_input_coins(inputs: &[String]) -> Result<Vec<UtxoId>> {
+    inputs
+        .iter()
+        .map(|input| {
+            input
+                .parse::<UtxoId>()
+                .map_err(|e| anyhow::anyhow!("Invalid input coin `{input}` - {e}"))
+        })
+        .collect()
+}
+
+/// Submits the given deployment transaction, retrying as the policy allows if the node can't be
+/// reached.
+///
+/// A transaction the node already received is not submitted again, as the node may have received
+/// an earlier submission whose response was lost, and would reject the resubmission. For the same
+/// reason, the transaction is reported in flight if the deployment is cancelled between attempts.
+async fn submit_transaction(
+    client: &FuelClient,
+    tx: &Transaction,
+    tx_id: fuel_tx::TxId,
+    policy: &DeployPolicy,
+    cancellation: &CancellationToken,
+) -> Result<()> {
+    let mut resubmission = false;
+    policy
+        .retry(
+            "submit the deployment transaction",
+            is_transport_error,
+            cancellation,
+            || {
+                let resubmission = std::mem::replace(&mut resubmission, true);
+                async move {
+                    if resubmission && client.transaction_status(&tx_id).await.is_ok() {
+                        info!("Transaction 0x{tx_id} was already received by the node");
+                        return Ok(());
+                    }
+                    client.submit(tx).await?;
+                    Ok(())
+                }
+            },
+        )
+        .await
+        .map_err(|e| match e.downcast::<DeployCancelled>() {
+            Ok(cancelled) => DeployCancelled {
+                in_flight: Some(tx_id),
+                ..cancelled
+            }
+            .into(),
+            Err(e) => e,
+        })
+}
+
+/// Whether the given error failed a request to the node before it got a response, eg. a dropped
+/// connection, rather than the node rejecting the request, which would be rejected again if
+/// retried.
+fn is_transport_error(e: &anyhow::Error) -> bool {
+    // The node client reports HTTP failures as I/O errors wrapping them.
+    e.chain().any(|cause| {
+        cause.is::<reqwest::Error>()
+            || cause
+                .downcast_ref::<std::io::Error>()
+                .and_then(|e| e.get_ref())
+                .is_some_and(|inner| inner.is::<reqwest::Error>())
+    })
+}
+
+/// Returns the tip to pay for each deployment transaction: the `--gas-price` if set, otherwise the
+/// one recommended by the `--gas-oracle`, if any.
+///
+/// The tip is scaled by the `--tip-multiplier`, if any.
+async fn resolve_tip(command: &cmd::Deploy, policy: &DeployPolicy) -> Option<u64> {
+    let tip = base_tip(command, policy).await;
+    let Some(multiplier) = command.tip_multiplier else {
+        return tip;
+    };
+    match tip {
+        Some(tip) => {
+            let scaled = scale_tip(tip, multiplier);
+            info!("Scaling the tip of {tip} by {multiplier} to {scaled}");
+            Some(scaled)
+        }
+        None => {
+            println_warning("There is no tip for `--tip-multiplier` to scale, pass `--gas-price` or `--gas-oracle`. Deploying without a tip.");
+            None
+        }
+    }
+}
+
+/// Returns the tip given by `--gas-price` or recommended by the `--gas-oracle`, before scaling.
+async fn base_tip(command: &cmd::Deploy, policy: &DeployPolicy) -> Option<u64> {
+    if let Some(tip) = command.gas.price {
+        return Some(tip);
+    }
+    let oracle_url = command.gas_oracle.as_deref()?;
+    match fetch_oracle_tip(oracle_url, policy.connect_timeout).await {
+        Ok(tip) => {
+            info!("Using a tip of {tip} recommended by the gas oracle at {oracle_url}");
+            Some(tip)
+        }
+        Err(e) => {
+            println_warning(&format!(
+                "Failed to fetch a tip from the gas oracle at {oracle_url}: {e}. Deploying without a tip."
+            ));
+            None
+        }
+    }
+}
+
+/// Scales the given tip by `multiplier`, rounding to the nearest unit.
+fn scale_tip(tip: u64, multiplier: f64) -> u64 {
+    // Float to integer casts saturate, so huge multipliers yield the maximum tip.
+    (tip as f64 * multiplier).round() as u64
+}
+
+/// Estimates the maximum fee of the given deployment transaction, in base asset units, at the gas
+/// price expected over the next blocks.
+async fn estimate_fee(
+    tx: &Transaction,
+    provider: &Provider,
+    consensus_parameters: &ConsensusParameters,
+) -> Result<u64> {
+    let Transaction::Create(create) = tx else {
+        bail!("Expected a contract deployment transaction");
+    };
+    let gas_price = provider
+        .estimate_gas_price(GAS_PRICE_BLOCK_HORIZON)
+        .await?
+        .gas_price;
+    let fee = fuel_tx::TransactionFee::checked_from_tx(
+        consensus_parameters.gas_costs(),
+        consensus_parameters.fee_params(),
+        create,
+        gas_price,
+    )
+    .context("Failed to estimate the fee of the deployment transaction")?;
+    Ok(fee.max_fee())
+}
+
+/// Formats an amount of base asset units as a decimal number with the given number of decimals,
+/// eg. `1_500_000` with 6 decimals as `1.5`.
+fn format_units(amount: u64, decimals: u8) -> String {
+    let decimals = usize::from(decimals);
+    let digits = format!("{amount:0>width$}", width = decimals + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - decimals);
+    match fraction.trim_end_matches('0') {
+        "" => whole.to_string(),
+        fraction => format!("{whole}.{fraction}"),
+    }
+}
+
+/// Prints the estimated fee of a deployment with the given number of decimals, along with its
+/// approximate fiat cost if the price of one whole coin of the base asset is given.
+fn print_fee_estimate(estimated_fee: u64, decimals: u8, price: Option<f64>) {
+    info!(
+        "Estimated fee: {} ({estimated_fee} base units), an approximation at the expected gas price",
+        format_units(estimated_fee, decimals)
+    );
+    if let Some(price) = price {
+        let cost = estimated_fee as f64 / 10f64.powi(i32::from(decimals)) * price;
+        info!("Approximate cost: {cost:.2} at a price of {price} per coin");
+    }
+}
+
+/// Fetches the recommended tip from the gas oracle at `url`.
+async fn fetch_oracle_tip(url: &str, timeout: Duration) -> Result<u64> {
+    let response = reqwest::Client::new()
+        .get(url)
+        .timeout(timeout)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    parse_oracle_tip(&response)
+}
+
+/// Parses a gas oracle response, a JSON object with the recommended tip in its `tip` field.
+fn parse_oracle_tip(response: &str) -> Result<u64> {
+    #[derive(Deserialize)]
+    struct OracleResponse {
+        tip: u64,
+    }
+    let response: OracleResponse = serde_json::from_str(response)
+        .context("Invalid gas oracle response, expected a JSON object with a `tip` field")?;
+    Ok(response.tip)
+}
+
+/// Deploy a single pkg given deploy command and the manifest file
+///
+/// The details of the node are queried for this deployment alone, [deploy] reuses them across the
+/// contracts it deploys.
+pub async fn deploy_pkg(
+    command: &cmd::Deploy,
+    manifest: &PackageManifestFile,
+    compiled: &BuiltPackage,
+    salt: Salt,
+) -> Result<DeployedContract> {
+    let policy = DeployPolicy::from(command);
+    let artifact_password = if command.encrypt_artifact {
+        Some(prompt_artifact_password()?)
+    } else {
+        None
+    };
+    let mut context = DeployContext {
+        tip: resolve_tip(command, &policy).await,
+        policy,
+        wallet_mode: wallet_mode(command)?,
+        artifact_password,
+        tx_builder_hook: None,
+        node_info_cache: NodeInfoCache {
+            check_node_version: !command.skip_node_version_check,
+            ..Default::default()
+        },
+        spent_inputs: SpentInputs::default(),
+        cancellation: CancellationToken::default(),
+    };
+    deploy_instance(
+        command,
+        manifest,
+        compiled,
+        None,
+        salt,
+        SaltSource::Explicit,
+        &mut context,
+    )
+    .await
+}
+
+/// Reports whether the given `--maturity` delays the deployment, warning when it is not ahead of
+/// the current block height of the node.
+async fn report_maturity(maturity: u32, provider: &Provider, node_url: &str) -> Result<()> {
+    if maturity == 0 {
+        return Ok(());
+    }
+    let current_height = provider.latest_block_height().await?;
+    if maturity <= current_height {
+        println_warning(&format!(
+            "The maturity {maturity} is not ahead of the current block height {current_height} of {node_url}, \
+            so it doesn't delay the deployment"
+        ));
+    } else {
+        info!("The deployment can't be included before block {maturity}, the current block height is {current_height}");
+    }
+    Ok(())
+}
+
+/// Deploys the given contract, with the storage slots of the given `--override-storage-slots`
+/// file if any, reusing the state shared by the deployments of the given context.
+async fn deploy_instance(
+    command: &cmd::Deploy,
+    manifest: &PackageManifestFile,
+    compiled: &BuiltPackage,
+    storage_slots_file: Option<&str>,
+    salt: Salt,
+    salt_source: SaltSource,
+    context: &mut DeployContext,
+) -> Result<DeployedContract> {
+    let DeployContext {
+        ref policy,
+        tip,
+        ref wallet_mode,
+        ref artifact_password,
+        ref tx_builder_hook,
+        ref mut node_info_cache,
+        ref mut spent_inputs,
+        ref cancellation,
+    } = *context;
+    let node_url = get_node_url(&command.node, &manifest.network)?;
+    let client = FuelClient::new(node_url.clone())?;
+    let NodeInfo {
+        provider,
+        consensus_parameters,
+        genesis_block_id,
+        submit_timeout,
+    } = cancellation
+        .run(node_info_cache.get(&node_url, &client, policy))
+        .await??;
+
+    let signing_key = match (&command.hd_path, wallet_mode) {
+        (Some(hd_path), WalletSelectionMode::ForcWallet(password)) => {
+            let signing_key =
+                secret_key_from_forc_wallet_path(&default_wallet_path(), hd_path, password)?;
+            info!(
+                "Signing with {} derived at {hd_path}",
+                bech32_from_secret(&signing_key)?
+            );
+            signing_key
+        }
+        _ => cancellation
+            .run(select_secret_key(
+                wallet_mode,
+                command.default_signer || command.unsigned,
+                command.signing_key,
+                &provider,
+                command.yes,
+            ))
+            .await??
+            .ok_or_else(|| anyhow::anyhow!("failed to select a signer for the transaction"))?,
+    };
+    let wallet = WalletUnlocked::new_from_private_key(signing_key, Some(provider.clone()));
+
+    let (mut bytecode, storage_slots) = deployed_code(command, compiled, storage_slots_file)?;
+    if let Some(name) = &command.set_deployer_configurable {
+        let deployer: Address = wallet.address().into();
+        if set_address_configurable(&mut bytecode, &compiled.program_abi, name, &deployer)? {
+            info!("Set configurable {name} to the deployer address 0x{deployer}");
+        }
+    }
+    let bytecode = &bytecode;
+    let (salt, salt_source) = if command.salt_auto_bump {
+        let bumped = cancellation
+            .run(bump_salt_until_free(
+                &client,
+                bytecode,
+                &storage_slots,
+                salt,
+            ))
+            .await??;
+        if bumped == salt {
+            (salt, salt_source)
+        } else {
+            info!("Deploying with the bumped salt 0x{bumped}");
+            (bumped, SaltSource::Bumped)
+        }
+    } else {
+        (salt, salt_source)
+    };
+    let ContractIdentity {
+        root,
+        state_root,
+        contract_id,
+    } = ContractIdentity::new(bytecode, &storage_slots, &salt);
+    print_storage_slots_summary(compiled, &storage_slots, storage_slots_file);
+    let size_breakdown = if command.size_breakdown {
+        let size_breakdown = SizeBreakdown::of(bytecode, &compiled.program_abi)?;
+        size_breakdown.print(manifest.project_name());
+        Some(size_breakdown)
+    } else {
+        None
+    };
+    if command.verbose {
+        info!("Salt: 0x{salt}");
+        info!("Bytecode root: 0x{root}");
+        info!("State root: 0x{state_root}");
+        info!("Contract ID: 0x{contract_id}");
+    }
+    if command.fail_on_existing
+        && cancellation
+            .run(client.contract(&contract_id))
+            .await??
+            .is_some()
+    {
+        bail!(
+            "Contract 0x{contract_id} already exists at {node_url}: this salt and bytecode were \
+            deployed before. Pass a different salt to deploy a fresh instance"
+        );
+    }
+
+    let maturity = command.maturity.maturity;
+    cancellation
+        .run(report_maturity(maturity, &provider, &node_url))
+        .await??;
+    let rehearsal = match &command.fork {
+        Some(fork) => Some(Rehearsal {
+            fork: fork.clone(),
+            fork_block_height: cancellation.run(provider.latest_block_height()).await??,
+        }),
+        None => None,
+    };
+    let mut tx_policies = TxPolicies::default().with_maturity(maturity);
+    if let Some(tip) = tip {
+        if command.tip_multiplier.is_some() {
+            info!("Tip: {tip}");
+        }
+        tx_policies = tx_policies.with_tip(tip);
+    }
+
+    let mut tb = CreateTransactionBuilder::prepare_contract_deployment(
+        bytecode.clone(),
+        contract_id,
+        state_root,
+        salt,
+        storage_slots.clone(),
+        tx_policies,
+    );
+
+    wallet.add_witnesses(&mut tb)?;
+    // The deployer's witness stays in place, a fee payer only provides the inputs covering the
+    // fee.
+    let fee_payer = match command.fee_payer_key {
+        Some(fee_payer_key) => {
+            let fee_payer =
+                WalletUnlocked::new_from_private_key(fee_payer_key, Some(provider.clone()));
+            fee_payer.add_witnesses(&mut tb)?;
+            Some(fee_payer)
+        }
+        None => None,
+    };
+    if let Some(tx_builder_hook) = tx_builder_hook {
+        tx_builder_hook(&mut tb).context("The transaction builder hook failed")?;
+    }
+    let input_coins = parse_input_coins(&command.input_coins)?;
+    cancellation
+        .run(fund_deployment(
+            fee_payer.as_ref().unwrap_or(&wallet),
+            &mut tb,
+            &input_coins,
+            spent_inputs,
+            &provider,
+            &consensus_parameters,
+        ))
+        .await??;
+    let tx = cancellation.run(tb.build(provider.clone())).await??;
+    let tx = Transaction::from(tx);
+
+    let estimated_fee = match command.fee_decimals {
+        Some(decimals) => {
+            let estimated_fee = cancellation
+                .run(estimate_fee(&tx, &provider, &consensus_parameters))
+                .await??;
+            print_fee_estimate(estimated_fee, decimals, command.fee_price);
+            Some(estimated_fee)
+        }
+        None => None,
+    };
+
+    if let Some(snapshot_path) = &command.consensus_params_snapshot {
+        check_consensus_parameters_snapshot(snapshot_path, &consensus_parameters, &node_url)?;
+    }
+    let chain_id = consensus_parameters.chain_id();
+
+    let output_dir = deployments_output_dir(command, manifest)?;
+    if let Some(genesis_block_id) = &genesis_block_id {
+        if let Some(artifact_path) =
+            find_artifact_from_reset_network(&output_dir, &node_url, genesis_block_id)
+        {
+            println_warning(&format!(
+                "The network at {node_url} appears to have been reset since the deployment recorded in {}: \
+                its genesis block differs. Contracts deployed before the reset no longer exist.",
+                artifact_path.display()
+            ));
+        }
+    }
+
+    cancellation.check()?;
+    let tx_id = tx.id(&chain_id);
+    submit_transaction(&client, &tx, tx_id, policy, cancellation).await?;
+
+    // wait for the contract deployment to be committed, with a timeout, unless the user
+    // interrupts the deployment
+    let status = tokio::select! {
+        status = tokio::time::timeout(
+            submit_timeout,
+            poll_tx_status(
+                &client,
+                &tx_id,
+                policy.poll_interval,
+            ),
+        ) => status.with_context(|| {
+            format!(
+                "Timed out waiting for contract {} to deploy. The transaction may have been dropped.",
+                &contract_id
+            )
+        })??,
+        _ = cancellation.cancelled() => {
+            return Err(DeployCancelled {
+                committed: Vec::new(),
+                in_flight: Some(tx_id),
+            }
+            .into());
+        }
+    };
+
+    let (block_height, receipts) = match status {
+        TransactionStatus::Success {
+            block_height,
+            receipts,
+            ..
+        } => (block_height, receipts),
+        e => {
+            // The inputs of a failed deployment are spent all the same.
+            spent_inputs.record(&tx);
+            bail!(
+                "contract {} failed to deploy due to an error: {:?}",
+                &contract_id,
+                e
+            )
+        }
+    };
+
+    spent_inputs.record(&tx);
+
+    let pkg_name = manifest.project_name();
+    if command.fork.is_some() {
+        info!("\n\nContract {pkg_name} Deployed to the rehearsal fork!");
+    } else {
+        info!("\n\nContract {pkg_name} Deployed!");
+    }
+
+    let network_name = command
+        .network_name
+        .clone()
+        .or_else(|| known_network_name(&node_url));
+    match &network_name {
+        Some(network_name) => info!("\nNetwork: {network_name} ({node_url})"),
+        None => info!("\nNetwork: {node_url}"),
+    }
+    info!("Contract ID: 0x{contract_id}");
+    info!("Salt: 0x{salt} ({salt_source})");
+    info!("Deployed in block {}", &block_height);
+
+    // Create a deployment artifact.
+    let deployment_size = bytecode.len();
+    let deployment_artifact = DeploymentArtifact {
+        schema_version: DEPLOYMENT_ARTIFACT_SCHEMA_VERSION,
+        transaction_id: format!("0x{}", tx_id),
+        salt: format!("0x{}", salt),
+        network_endpoint: node_url.to_string(),
+        network_name: network_name.clone(),
+        chain_id,
+        contract_id: format!("0x{}", contract_id),
+        deployment_size,
+        deployed_block_height: *block_height,
+        created_at: Some(artifact_timestamp()?),
+        genesis_block_id,
+        abi: match &compiled.program_abi {
+            ProgramABI::Fuel(abi) => Some(abi.clone()),
+            _ => None,
+        },
+        storage_slot_count: storage_slots.len(),
+        storage_slots: command.record_storage_slots.then(|| storage_slots.clone()),
+        rehearsal,
+        tags: parse_tags(&command.tags)?,
+        salt_source: Some(salt_source),
+    };
+
+    let artifact_path = match artifact_password.as_deref() {
+        Some(password) => {
+            deployment_artifact.to_encrypted_file(&output_dir, pkg_name, contract_id, password)?
+        }
+        None => deployment_artifact.to_file(&output_dir, pkg_name, contract_id)?,
+    }
+    .canonicalize()?;
+    info!("Deployment artifact: {}", artifact_path.display());
+    if command.sign_artifact {
+        let signature_path =
+            ArtifactSignature::sign(&deployment_artifact, &signing_key)?.to_file(&artifact_path)?;
+        info!("Artifact signature: {}", signature_path.display());
+    }
+    if let Some(bundle_dir) = &command.bundle {
+        let bundle_path = bundle::Bundle {
+            pkg_name,
+            contract_id,
+            bytecode,
+            program_abi: &compiled.program_abi,
+            storage_slots: &storage_slots,
+            artifact: &deployment_artifact,
+        }
+        .write(bundle_dir)?;
+        info!("Deployment bundle: {}", bundle_path.display());
+    }
+    let abi_path = if command.print_abi_path {
+        let abi_path = build_abi_path(command, manifest).canonicalize()?;
+        info!("ABI: {}", abi_path.display());
+        Some(abi_path)
+    } else {
+        None
+    };
+
+    if command.json {
+        let mut deployment = serde_json::json!({
+            "contract": pkg_name,
+            "contract_id": format!("0x{contract_id}"),
+            "transaction_id": format!("0x{tx_id}"),
+            "salt": format!("0x{salt}"),
+            "salt_source": salt_source,
+            "network_endpoint": node_url,
+            "deployed_block_height": *block_height,
+            "artifact_path": artifact_path,
+        });
+        if let Some(abi_path) = abi_path {
+            deployment["abi_path"] = serde_json::json!(abi_path);
+        }
+        if let Some(network_name) = &network_name {
+            deployment["network_name"] = serde_json::json!(network_name);
+        }
+        if let Some(estimated_fee) = estimated_fee {
+            deployment["estimated_fee"] = serde_json::json!(estimated_fee);
+        }
+        if !deployment_artifact.tags.is_empty() {
+            deployment["tags"] = serde_json::json!(deployment_artifact.tags);
+        }
+        if let Some(size_breakdown) = &size_breakdown {
+            deployment["size_breakdown"] = serde_json::json!(size_breakdown);
+        }
+        let build_warnings = package_warnings(compiled);
+        if !build_warnings.is_empty() {
+            deployment["build_warnings"] = serde_json::json!(build_warnings);
+        }
+        println!("{deployment}");
+    }
+
+    Ok(DeployedContract {
+        id: contract_id,
+        receipts: command.receipts.then_some(receipts),
+    })
+}
+
+fn build_opts_from_cmd(cmd: &cmd::Deploy) -> pkg::BuildOpts {
+    pkg::BuildOpts {
+        pkg: pkg::PkgOpts {
+            path: cmd.pkg.path.clone(),
+            offline: cmd.pkg.offline || cmd.frozen,
+            terse: cmd.pkg.terse,
+            locked: cmd.pkg.locked || cmd.frozen,
+            output_directory: cmd.pkg.output_directory.clone(),
+            json_abi_with_callpaths: cmd.pkg.json_abi_with_callpaths,
+            ipfs_node: cmd.pkg.ipfs_node.clone().unwrap_or_default(),
+        },
+        print: pkg::PrintOpts {
+            ast: cmd.print.ast,
+            dca_graph: cmd.print.dca_graph.clone(),
+            dca_graph_url_format: cmd.print.dca_graph_url_format.clone(),
+            asm: cmd.print.asm(),
+            bytecode: cmd.print.bytecode,
+            bytecode_spans: false,
+            ir: cmd.print.ir(),
+            reverse_order: cmd.print.reverse_order,
+        },
+        time_phases: cmd.print.time_phases,
+        metrics_outfile: cmd.print.metrics_outfile.clone(),
+        minify: pkg::MinifyOpts {
+            json_abi: cmd.minify.json_abi,
+            json_storage_slots: cmd.minify.json_storage_slots,
+        },
+        build_profile: cmd.build_profile.clone(),
+        release: cmd.build_profile == BuildProfile::RELEASE,
+        error_on_warnings: false,
+        binary_outfile: cmd.build_output.bin_file.clone(),
+        debug_outfile: cmd.build_output.debug_file.clone(),
+        build_target: BuildTarget::default(),
+        tests: false,
+        member_filter: pkg::MemberFilter {
+            build_predicates: cmd.include_predicates,
+            ..pkg::MemberFilter::only_contracts()
+        },
+        experimental: ExperimentalFlags {
+            new_encoding: !cmd.no_encoding_v1,
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn setup_manifest_files() -> BTreeMap<String, PackageManifestFile> {
+        let mut contract_to_manifest = BTreeMap::default();
+
+        let manifests_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("test")
+            .join("data");
+
+        for entry in manifests_dir.read_dir().unwrap() {
+            let manifest =
+                PackageManifestFile::from_file(entry.unwrap().path().join("Forc.toml")).unwrap();
+            contract_to_manifest.insert(manifest.project_name().to_string(), manifest);
+        }
+
+        contract_to_manifest
+    }
+
+    #[test]
+    fn test_parse_and_validate_salts_pass() {
+        let mut manifests = setup_manifest_files();
+        let mut expected = ContractSaltMap::new();
+        let mut salt_strs = vec![];
+
+        // Remove contracts with dependencies
+        manifests.remove("contract_with_dep_with_salt_conflict");
+        manifests.remove("contract_with_dep");
+
+        for (index, manifest) in manifests.values().enumerate() {
+            let salt = "0x0000000000000000000000000000000000000000000000000000000000000000";
+
+            let salt_str = format!("{}:{salt}", manifest.project_name());
+            salt_strs.push(salt_str.to_string());
+
+            expected.insert(
+                manifest.project_name().to_string(),
+                salt.parse::<Salt>().unwrap(),
+            );
+
+            let got = validate_and_parse_salts(&salt_strs, manifests.values()).unwrap();
+            assert_eq!(got.len(), index + 1);
+            assert_eq!(got, expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_and_validate_salts_duplicate_salt_input() {
+        let manifests = setup_manifest_files();
+        let first_name = manifests.first_key_value().unwrap().0;
+        let salt: Salt = "0x0000000000000000000000000000000000000000000000000000000000000000"
+            .parse()
+            .unwrap();
+        let salt_str = format!("{first_name}:{salt}");
+        let err_message =
+            format!("2 salts provided for contract '{first_name}':\n  {salt}\n  {salt}");
+
+        assert_eq!(
+            validate_and_parse_salts(&[salt_str.clone(), salt_str], manifests.values())
+                .unwrap_err()
+                .to_string(),
+            err_message,
+        );
+    }
+
+    #[test]
+    fn test_parse_single_salt_multiple_manifests_malformed_input() {
+        let manifests = setup_manifest_files();
+        let salt_str =
+            "contract_a=0x0000000000000000000000000000000000000000000000000000000000000000";
+        let err_message =
+            "Invalid salt provided - salt must be in the form <CONTRACT_NAME>:<SALT> when deploying a workspace";
+
+        assert_eq!(
+            validate_and_parse_salts(&[salt_str.to_string()], manifests.values())
+                .unwrap_err()
+                .to_string(),
+            err_message,
+        );
+    }
+
+    #[test]
+    fn test_parse_salt() {
+        let salt = "0x0000000000000000000000000000000000000000000000000000000000000001";
+        assert_eq!(parse_salt(salt).unwrap(), salt.parse::<Salt>().unwrap());
+        assert_eq!(
+            parse_salt(&salt[2..]).unwrap(),
+            salt.parse::<Salt>().unwrap()
+        );
+        assert_eq!(
+            parse_salt("0x01"),
+            Err(SaltParseError::TooShort {
+                salt: "0x01".to_string(),
+                len: 1
+            })
+        );
+        let too_long = format!("{salt}01");
+        assert_eq!(
+            parse_salt(&too_long),
+            Err(SaltParseError::TooLong {
+                salt: too_long,
+                len: 33
+            })
+        );
+        assert_eq!(
+            parse_salt("0xzz"),
+            Err(SaltParseError::BadHex {
+                salt: "0xzz".to_string(),
+                invalid: 'z'
+            })
+        );
+        assert_eq!(
+            parse_salt("0x001"),
+            Err(SaltParseError::OddLength("0x001".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_salt_guidance() {
+        let eth_address = "0x52908400098527886e0f7030069857d2e4169ee7";
+        assert_eq!(
+            parse_salt(eth_address).unwrap_err().to_string(),
+            format!("Invalid salt '{eth_address}' - salt must be 32 bytes long, but it is 20 bytes. This looks like an Ethereum address, not a salt")
+        );
+        let bech32_address = "fuel1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqsx2mt2";
+        assert!(parse_salt(bech32_address)
+            .unwrap_err()
+            .to_string()
+            .ends_with("but it contains 'u'. This looks like a bech32 address, not a salt"));
+    }
+
+    #[test]
+    fn test_parse_multiple_salts_conflict() {
+        let manifests = setup_manifest_files();
+        let salt_str =
+            "contract_with_dep:0x0000000000000000000000000000000000000000000000000000000000000001";
+        let err_message =
+            "Redeclaration of salt using the option '--salt' while a salt exists for contract 'contract_with_dep' \
+            under the contract dependencies of the Forc.toml manifest for 'contract_with_dep_with_salt_conflict'\n\
+            Existing salt: '0x0000000000000000000000000000000000000000000000000000000000000000',\n\
+            You declared: '0x0000000000000000000000000000000000000000000000000000000000000001'\n";
+
+        assert_eq!(
+            validate_and_parse_salts(&[salt_str.to_string()], manifests.values())
+                .unwrap_err()
+                .to_string(),
+            err_message,
+        );
+    }
+
+    #[test]
+    fn test_select_salt_precedence() {
+        let manifests = setup_manifest_files();
+        let manifest = &manifests["contract_with_network_salt"];
+        let network_salt: Salt =
+            "0x0000000000000000000000000000000000000000000000000000000000000001"
+                .parse()
+                .unwrap();
+        let cli_salt: Salt = "0x0000000000000000000000000000000000000000000000000000000000000002"
+            .parse()
+            .unwrap();
+        let node_target = NodeTarget::default();
+
+        // The manifest salt is used when no salt is given on the command line.
+        assert_eq!(
+            select_salt(None, false, &node_target, manifest).unwrap(),
+            network_salt
+        );
+
+        // Salts given on the command line take precedence over the manifest salt.
+        let map = ContractSaltMap::from([(manifest.project_name().to_string(), cli_salt)]);
+        assert_eq!(
+            select_salt(Some(&map), false, &node_target, manifest).unwrap(),
+            cli_salt
+        );
+        assert_eq!(
+            select_salt(None, true, &node_target, manifest).unwrap(),
+            Salt::default()
+        );
+        assert!(select_salt(Some(&map), true, &node_target, manifest).is_err());
+
+        // The manifest salt is ignored when deploying to a different node.
+        let node_target = NodeTarget {
+            node_url: Some("http://127.0.0.1:4000".to_string()),
+            ..Default::default()
+        };
+        assert_ne!(
+            select_salt(None, false, &node_target, manifest).unwrap(),
+            network_salt
+        );
+    }
+
+    #[test]
+    fn test_check_predictable_salt() {
+        let manifests = setup_manifest_files();
+        let command = cmd::Deploy::default();
+        check_predictable_salt(&command, None, &manifests["contract_with_network_salt"]).unwrap();
+        for name in ["standalone_contract", "contract_with_random_network_salt"] {
+            let manifest = &manifests[name];
+            let err = check_predictable_salt(&command, None, manifest).unwrap_err();
+            assert!(err.to_string().contains("random salt"), "{err}");
+
+            let map = ContractSaltMap::from([(name.to_string(), Salt::default())]);
+            check_predictable_salt(&command, Some(&map), manifest).unwrap();
+            let command = cmd::Deploy {
+                default_salt: true,
+                ..Default::default()
+            };
+            check_predictable_salt(&command, None, manifest).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_selected_salt() {
+        let manifests = setup_manifest_files();
+        let manifest = &manifests["contract_with_network_salt"];
+        let command = cmd::Deploy::default();
+        let network_salt = manifest_network_salt(&command.node, manifest).unwrap();
+        assert_eq!(
+            selected_salt(&command, None, None, manifest).unwrap(),
+            ((network_salt, network_salt), SaltSource::Manifest)
+        );
+
+        // The salt strategy takes precedence over the manifest, and is prefixed all the same.
+        let salt_strategy = ExplicitSalts(ContractSaltMap::from([(
+            manifest.project_name().to_string(),
+            Salt::new([1; 32]),
+        )]));
+        assert_eq!(
+            selected_salt(&command, Some(&salt_strategy), None, manifest).unwrap(),
+            (
+                (Salt::new([1; 32]), Salt::new([1; 32])),
+                SaltSource::Strategy
+            )
+        );
+        let command = cmd::Deploy {
+            salt_prefix: Some(Bytes32::new([2; 32])),
+            ..Default::default()
+        };
+        assert_eq!(
+            selected_salt(&command, Some(&salt_strategy), None, manifest).unwrap(),
+            (
+                (
+                    Salt::new([1; 32]),
+                    apply_salt_prefix(&Bytes32::new([2; 32]), &Salt::new([1; 32]))
+                ),
+                SaltSource::Strategy
+            )
+        );
+    }
+
+    #[test]
+    fn test_salt_source() {
+        let manifests = setup_manifest_files();
+        let manifest = &manifests["contract_with_network_salt"];
+        let map = ContractSaltMap::from([(manifest.project_name().to_string(), Salt::default())]);
+        let other_map = ContractSaltMap::from([("other".to_string(), Salt::default())]);
+        let command = cmd::Deploy::default();
+        assert_eq!(salt_source(&command, None, manifest), SaltSource::Manifest);
+        assert_eq!(
+            salt_source(&command, Some(&map), manifest),
+            SaltSource::Explicit
+        );
+        assert_eq!(
+            salt_source(&command, Some(&other_map), manifest),
+            SaltSource::Default
+        );
+
+        let command = cmd::Deploy {
+            salt_file: Some(PathBuf::from("salts.toml")),
+            ..Default::default()
+        };
+        assert_eq!(
+            salt_source(&command, Some(&map), manifest),
+            SaltSource::File
+        );
+        let command = cmd::Deploy {
+            salt_from_git: true,
+            ..Default::default()
+        };
+        assert_eq!(salt_source(&command, Some(&map), manifest), SaltSource::Git);
+        let command = cmd::Deploy {
+            default_salt: true,
+            ..Default::default()
+        };
+        assert_eq!(salt_source(&command, None, manifest), SaltSource::Default);
+        let command = cmd::Deploy {
+            node: NodeTarget {
+                node_url: Some("http://127.0.0.1:4000".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert_eq!(salt_source(&command, None, manifest), SaltSource::Random);
+    }
+
+    #[test]
+    fn test_ensure_unique_storage_slot_keys() {
+        let slot = |key: u8, value: u8| {
+            StorageSlot::new(Bytes32::new([key; 32]), Bytes32::new([value; 32]))
+        };
+
+        let mut storage_slots = vec![slot(2, 0), slot(1, 0)];
+        storage_slots.sort();
+        assert!(ensure_unique_storage_slot_keys(&storage_slots).is_ok());
+
+        let mut storage_slots = vec![slot(1, 0), slot(2, 0), slot(1, 1)];
+        storage_slots.sort();
+        let err = ensure_unique_storage_slot_keys(&storage_slots).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains(&format!("0x{}", Bytes32::new([1; 32]))));
+    }
+
+    #[test]
+    fn test_build_abi_path() {
+        let manifests = setup_manifest_files();
+        let manifest = &manifests["standalone_contract"];
+        let mut command = cmd::Deploy {
+            build_profile: BuildProfile::RELEASE.to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            build_abi_path(&command, manifest),
+            default_output_directory(manifest.dir())
+                .join("release")
+                .join("standalone_contract-abi.json")
+        );
+
+        // An explicit output directory is used as is, like the build does.
+        command.pkg.output_directory = Some("custom_out".to_string());
+        assert_eq!(
+            build_abi_path(&command, manifest),
+            Path::new("custom_out").join("standalone_contract-abi.json")
+        );
+    }
+
+    #[test]
+    fn test_build_opts_frozen() {
+        let build_opts = build_opts_from_cmd(&cmd::Deploy::default());
+        assert!(!build_opts.pkg.locked);
+        assert!(!build_opts.pkg.offline);
+
+        let build_opts = build_opts_from_cmd(&cmd::Deploy {
+            frozen: true,
+            ..Default::default()
+        });
+        assert!(build_opts.pkg.locked);
+        assert!(build_opts.pkg.offline);
+    }
+
+    #[test]
+    fn test_default_build_profile_is_release() {
+        let command = cmd::Deploy::default();
+        assert_eq!(command.build_profile, BuildProfile::RELEASE);
+        // The release profile is deployable anywhere without `--allow-debug-deploy`.
+        check_build_profile(&command, &[]).unwrap();
+        assert!(build_opts_from_cmd(&command).release);
+    }
+
+    #[test]
+    fn test_check_deployable_members() {
+        let data_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("test")
+            .join("data");
+        assert!(check_deployable_members(&data_dir.join("standalone_contract"), false).is_ok());
+        let err = check_deployable_members(&data_dir.join("standalone_script"), false).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`standalone_script` is a script, not a contract: only contracts can be deployed"
+        );
+    }
+
+    #[test]
+    fn test_check_unique_names() {
+        let (a, b) = (Path::new("a/token"), Path::new("b/token"));
+        assert!(check_unique_names([("token", a), ("vault", b)].into_iter()).is_ok());
+        let err = check_unique_names([("token", a), ("token", b)].into_iter()).unwrap_err();
+        let err = err.to_string();
+        assert!(err.contains("`token`"), "{err}");
+        assert!(err.contains("a/token") && err.contains("b/token"), "{err}");
+    }
+
+    #[test]
+    fn test_scale_tip() {
+        assert_eq!(scale_tip(100, 1.5), 150);
+        assert_eq!(scale_tip(3, 0.5), 2);
+        assert_eq!(scale_tip(100, 0.0), 0);
+        assert_eq!(scale_tip(u64::MAX, 2.0), u64::MAX);
+    }
+
+    #[test]
+    fn test_format_units() {
+        assert_eq!(format_units(1_500_000, 6), "1.5");
+        assert_eq!(format_units(1_234, 9), "0.000001234");
+        assert_eq!(format_units(2_000_000_000, 9), "2");
+        assert_eq!(format_units(0, 9), "0");
+        assert_eq!(format_units(42, 0), "42");
+    }
+
+    #[test]
+    fn test_parse_oracle_tip() {
+        assert_eq!(parse_oracle_tip(r#"{"tip": 42}"#).unwrap(), 42);
+        assert_eq!(
+            parse_oracle_tip(r#"{"tip": 42, "gas_price": 1}"#).unwrap(),
+            42
+        );
+        assert!(parse_oracle_tip(r#"{"gas_price": 1}"#).is_err());
+        assert!(parse_oracle_tip("42").is_err());
+    }
+
+    #[test]
+    fn test_validate_deployments_dir() {
+        for valid in ["deployments", "forc/deployments", "./forc/deployments"] {
+            assert_eq!(validate_deployments_dir(valid).unwrap(), Path::new(valid));
+        }
+        for invalid in [
+            "",
+            ".",
+            "../deployments",
+            "forc/../../deployments",
+            "/deployments",
+        ] {
+            assert!(validate_deployments_dir(invalid).is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_parse_tags() {
+        let tags = parse_tags(&["ticket=OPS-42".to_string(), "note=a=b".to_string()]).unwrap();
+        assert_eq!(tags["ticket"], "OPS-42");
+        assert_eq!(tags["note"], "a=b");
+        assert!(parse_tags(&[]).unwrap().is_empty());
+        assert!(parse_tags(&["ticket".to_string()]).is_err());
+        assert!(parse_tags(&["=OPS-42".to_string()]).is_err());
+        assert!(parse_tags(&["ticket=1".to_string(), "ticket=2".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_target_fork() {
+        let fork = "http://127.0.0.1:4001/v1/graphql".to_string();
+        let mut command = cmd::Deploy::default();
+        target_fork(&mut command, fork.clone()).unwrap();
+        assert_eq!(command.node.node_url, Some(fork.clone()));
+
+        let mut command = cmd::Deploy {
+            node: NodeTarget {
+                testnet: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(target_fork(&mut command, fork).is_err());
+    }
+
+    #[test]
+    fn test_consensus_parameters_snapshot() {
+        let dir = tempfile::tempdir().unwrap();
+        let snapshot_path = dir.path().join("consensus_parameters.json");
+        let consensus_parameters = ConsensusParameters::default();
+
+        check_consensus_parameters_snapshot(
+            &snapshot_path,
+            &consensus_parameters,
+            crate::constants::NODE_URL,
+        )
+        .unwrap();
+        let snapshot: ConsensusParameters =
+            serde_json::from_str(&std::fs::read_to_string(&snapshot_path).unwrap()).unwrap();
+        assert_eq!(snapshot, consensus_parameters);
+
+        // A mismatch only warns.
+        let mut changed = consensus_parameters.clone();
+        changed.set_chain_id(ChainId::new(1));
+        check_consensus_parameters_snapshot(&snapshot_path, &changed, crate::constants::NODE_URL)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_storage_slots_json() {
+        let slot = |key: u8| StorageSlot::new(Bytes32::new([key; 32]), Bytes32::zeroed());
+        let storage_slots = [slot(2), slot(1)];
+        let sorted = serde_json::to_value([slot(1), slot(2)]).unwrap();
+
+        let json = storage_slots_json(&[("contract_a", &storage_slots[..])]).unwrap();
+        assert_eq!(json, sorted);
+
+        let json =
+            storage_slots_json(&[("contract_a", &storage_slots[..]), ("contract_b", &[])]).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "contract_a": sorted, "contract_b": [] })
+        );
+    }
+
+    #[test]
+    fn test_apply_salt_prefix() {
+        let prefix = Bytes32::new([1; 32]);
+        let salt = Salt::default();
+        let effective_salt = apply_salt_prefix(&prefix, &salt);
+        assert_ne!(effective_salt, salt);
+        assert_eq!(apply_salt_prefix(&prefix, &salt), effective_salt);
+        assert_ne!(
+            apply_salt_prefix(&Bytes32::new([2; 32]), &salt),
+            effective_salt
+        );
+    }
+
+    #[test]
+    fn test_contract_identity() {
+        let bytecode = vec![0u8; 16];
+        let storage_slots = vec![StorageSlot::new(Bytes32::new([1; 32]), Bytes32::zeroed())];
+        let identity = ContractIdentity::new(&bytecode, &storage_slots, &Salt::default());
+        let contract = Contract::from(bytecode.clone());
+        assert_eq!(identity.root, contract.root());
+        assert_eq!(
+            identity.state_root,
+            Contract::initial_state_root(storage_slots.iter())
+        );
+        assert_eq!(
+            identity.contract_id,
+            contract.id(&Salt::default(), &identity.root, &identity.state_root)
+        );
+
+        // The ID depends on the salt and the storage slots, the roots only on their own input.
+        let salted = ContractIdentity::new(&bytecode, &storage_slots, &Salt::new([1; 32]));
+        assert_eq!(salted.root, identity.root);
+        assert_ne!(salted.contract_id, identity.contract_id);
+        let empty_storage = ContractIdentity::new(&bytecode, &[], &Salt::default());
+        assert_ne!(empty_storage.state_root, identity.state_root);
+        assert_ne!(empty_storage.contract_id, identity.contract_id);
+    }
+
+    #[test]
+    fn test_check_storage_slot_instances() {
+        let files = |count: usize| (0..count).map(|i| format!("{i}.json")).collect::<Vec<_>>();
+        check_storage_slot_instances(&cmd::Deploy {
+            override_storage_slots: files(2),
+            ..Default::default()
+        })
+        .unwrap();
+
+        let recording_per_name = || {
+            [
+                cmd::Deploy {
+                    write_salts: Some(PathBuf::from("salts.toml")),
+                    ..Default::default()
+                },
+                cmd::Deploy {
+                    post_deploy_cmd: Some("true".to_string()),
+                    ..Default::default()
+                },
+                cmd::Deploy {
+                    if_abi_changed: true,
+                    ..Default::default()
+                },
+            ]
+        };
+        for command in recording_per_name() {
+            let command = cmd::Deploy {
+                override_storage_slots: files(2),
+                ..command
+            };
+            assert!(check_storage_slot_instances(&command).is_err());
+        }
+        // A single instance of each contract is recorded under the contract's name as usual.
+        for command in recording_per_name() {
+            let command = cmd::Deploy {
+                override_storage_slots: files(1),
+                ..command
+            };
+            check_storage_slot_instances(&command).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_read_storage_slots_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let slot = |key: u8| {
+            format!(
+                r#"{{"key":"{}","value":"{}"}}"#,
+                Bytes32::new([key; 32]),
+                Bytes32::zeroed()
+            )
+        };
+
+        let path = dir.path().join("slots.json");
+        std::fs::write(&path, format!("[{},{}]", slot(2), slot(1))).unwrap();
+        let storage_slots = read_storage_slots_file(path.to_str().unwrap()).unwrap();
+        assert_eq!(storage_slots.len(), 2);
+        assert_eq!(*storage_slots[0].key(), Bytes32::new([1; 32]));
+
+        // Each file is validated on its own, and errors name the offending file.
+        let duplicated = dir.path().join("duplicated.json");
+        std::fs::write(&duplicated, format!("[{},{}]", slot(1), slot(1))).unwrap();
+        let err = read_storage_slots_file(duplicated.to_str().unwrap()).unwrap_err();
+        assert!(err.to_string().contains("duplicated.json"));
+    }
+
+    #[test]
+    fn test_compute_contract_id() {
+        let bytecode = vec![0u8; 16];
+        let slot_a = StorageSlot::new(Bytes32::new([1; 32]), Bytes32::zeroed());
+        let slot_b = StorageSlot::new(Bytes32::new([2; 32]), Bytes32::new([3; 32]));
+        let salt = Salt::new([4; 32]);
+        let contract_id = compute_contract_id(&bytecode, &salt, &[slot_a.clone(), slot_b.clone()]);
+        assert_eq!(
+            contract_id,
+            ContractIdentity::new(&bytecode, &[slot_a.clone(), slot_b.clone()], &salt).contract_id
+        );
+        // The order of the storage slots doesn't matter.
+        assert_eq!(
+            compute_contract_id(&bytecode, &salt, &[slot_b, slot_a]),
+            contract_id
+        );
+    }
+
+    #[test]
+    fn test_salt_from_git_commit() {
+        let commit = "0123456789abcdef0123456789abcdef01234567";
+        let salt = salt_from_git_commit(commit, "contract_a");
+        assert_eq!(salt_from_git_commit(commit, "contract_a"), salt);
+        assert_ne!(salt_from_git_commit(commit, "contract_b"), salt);
+        assert_ne!(
+            salt_from_git_commit("fedcba9876543210fedcba9876543210fedcba98", "contract_a"),
+            salt
+        );
+    }
+
+    #[test]
+    fn test_git_head_commit() {
+        let repo_dir = tempfile::tempdir().unwrap();
+        let repo = git2::Repository::init(repo_dir.path()).unwrap();
+        std::fs::write(repo_dir.path().join("Forc.toml"), "").unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("Forc.toml")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = git2::Signature::now("forc", "forc@fuel.network").unwrap();
+        let commit = repo
+            .commit(Some("HEAD"), &signature, &signature, "init", &tree, &[])
+            .unwrap();
+        let src_dir = repo_dir.path().join("src");
+        std::fs::create_dir(&src_dir).unwrap();
+        assert_eq!(
+            git_head_commit(&src_dir, false).unwrap(),
+            commit.to_string()
+        );
+
+        // Untracked files make the working tree dirty.
+        std::fs::write(src_dir.join("main.sw"), "contract;").unwrap();
+        assert!(git_head_commit(repo_dir.path(), false).is_err());
+        assert_eq!(
+            git_head_commit(repo_dir.path(), true).unwrap(),
+            commit.to_string()
+        );
+    }
+
+    #[test]
+    fn test_deploy_report_into_result() {
+        let deployed = DeployedContract {
+            id: ContractId::default(),
+            receipts: None,
+        };
+        let report = DeployReport {
+            succeeded: vec![DeployedContract {
+                id: ContractId::default(),
+                receipts: None,
+            }],
+            failed: vec![],
+            warnings: vec![],
+        };
+        assert_eq!(report.into_result().unwrap(), vec![deployed]);
+
+        let report = DeployReport {
+            succeeded: vec![],
+            failed: vec![("contract_a".to_string(), anyhow::anyhow!("out of gas"))],
+            warnings: vec![],
+        };
+        assert_eq!(report.into_result().unwrap_err().to_string(), "out of gas");
+
+        let report = DeployReport {
+            succeeded: vec![],
+            failed: vec![
+                ("contract_a".to_string(), anyhow::anyhow!("out of gas")),
+                ("contract_b".to_string(), anyhow::anyhow!("timed out")),
+            ],
+            warnings: vec![],
+        };
+        assert_eq!(
+            report.into_result().unwrap_err().to_string(),
+            "2 of 2 contracts failed to deploy:\n  contract_a: out of gas\n  contract_b: timed out"
+        );
+    }
+
+    #[test]
+    fn test_is_supported_node_version() {
+        assert!(is_supported_node_version(SUPPORTED_FUEL_CORE_VERSION));
+        assert!(is_supported_node_version("v0.31.9"));
+        assert!(!is_supported_node_version("0.32.0"));
+        assert!(!is_supported_node_version("1.31.0"));
+        assert!(!is_supported_node_version("unknown"));
+    }
+
+    #[test]
+    fn test_parse_source_date_epoch() {
+        assert_eq!(
+            parse_source_date_epoch("1700000000").unwrap(),
+            1_700_000_000
+        );
+        assert_eq!(parse_source_date_epoch("0\n").unwrap(), 0);
+        assert!(parse_source_date_epoch("yesterday").is_err());
+        assert!(parse_source_date_epoch("-1").is_err());
+    }
+
+    #[test]
+    fn test_spent_inputs_record() {
+        let utxo_id = UtxoId::new(Bytes32::new([1; 32]), 0);
+        let mut tb = TransactionBuilder::create(Witness::default(), Salt::default(), vec![]);
+        tb.add_input(Input::coin_signed(
+            utxo_id,
+            Address::default(),
+            1,
+            AssetId::default(),
+            Default::default(),
+            0,
+        ));
+        let mut spent_inputs = SpentInputs::default();
+        spent_inputs.record(&Transaction::from(tb.finalize_without_signature_inner()));
+        assert_eq!(spent_inputs.0, vec![CoinTypeId::UtxoId(utxo_id)]);
+    }
+
+    #[test]
+    fn test_extend_submit_timeout() {
+        let node_url = "https://testnet.fuel.network";
+        let timeout = Duration::from_secs(30);
+        assert_eq!(
+            extend_submit_timeout(node_url, timeout, Duration::from_secs(1)),
+            timeout
+        );
+        assert_eq!(
+            extend_submit_timeout(node_url, timeout, Duration::from_secs(10)),
+            timeout
+        );
+        assert_eq!(
+            extend_submit_timeout(node_url, timeout, Duration::from_secs(20)),
+            Duration::from_secs(60)
+        );
+    }
+
+    #[test]
+    fn test_next_salt() {
+        assert_eq!(
+            next_salt(&Salt::default()),
+            Salt::new({
+                let mut bytes = [0; 32];
+                bytes[31] = 1;
+                bytes
+            })
+        );
+        let mut bytes = [0; 32];
+        bytes[30] = 0x01;
+        bytes[31] = 0xff;
+        let mut expected = [0; 32];
+        expected[30] = 0x02;
+        assert_eq!(next_salt(&Salt::new(bytes)), Salt::new(expected));
+        assert_eq!(next_salt(&Salt::new([0xff; 32])), Salt::default());
+    }
+
+    #[test]
+    fn test_is_transport_error() {
+        let http_error = reqwest::Client::new()
+            .get("http://[::1")
+            .build()
+            .unwrap_err();
+        let transport = std::io::Error::new(std::io::ErrorKind::Other, http_error);
+        assert!(is_transport_error(&transport.into()));
+
+        // Rejections by the node would recur on resubmission.
+        let rejected = std::io::Error::new(
+            std::io::ErrorKind::Other,
+            "Response errors; Transaction is not inserted. Hash is already known",
+        );
+        assert!(!is_transport_error(&rejected.into()));
+    }
+
+    #[test]
+    fn test_parse_input_coins() {
+        let utxo_id = UtxoId::new(Bytes32::new([1; 32]), 2);
+        assert_eq!(
+            parse_input_coins(&[format!("{utxo_id:#x}")]).unwrap(),
+            vec![utxo_id]
+        );
+        assert!(parse_input_coins(&[]).unwrap().is_empty());
+        assert!(parse_input_coins(&["0xzz".to_string()]).is_err());
+    }
+
+    pub(super) fn test_deployment_artifact() -> DeploymentArtifact {
+        DeploymentArtifact {
+            schema_version: DEPLOYMENT_ARTIFACT_SCHEMA_VERSION,
+            transaction_id: format!("0x{}", fuel_tx::TxId::default()),
+            salt: format!("0x{}", Salt::default()),
+            network_endpoint: crate::constants::NODE_URL.to_string(),
+            network_name: None,
+            chain_id: ChainId::default(),
+            contract_id: format!("0x{}", ContractId::default()),
+            deployment_size: 0,
+            deployed_block_height: 0,
+            created_at: None,
+            genesis_block_id: None,
+            abi: None,
+            storage_slot_count: 0,
+            storage_slots: None,
+            rehearsal: None,
+            tags: BTreeMap::new(),
+            salt_source: None,
+        }
+    }
+
+    #[test]
+    fn test_find_artifact_from_reset_network() {
+        let output_dir = tempfile::tempdir().unwrap();
+        let endpoint = crate::constants::NODE_URL;
+        let genesis = format!("0x{}", Bytes32::new([1; 32]));
+        let artifact = DeploymentArtifact {
+            genesis_block_id: Some(genesis.clone()),
+            ..test_deployment_artifact()
+        };
+        artifact
+            .to_file(output_dir.path(), "contract", ContractId::default())
+            .unwrap();
+
+        // Same chain, or a different network altogether.
+        assert!(find_artifact_from_reset_network(output_dir.path(), endpoint, &genesis).is_none());
+        assert!(find_artifact_from_reset_network(
+            output_dir.path(),
+            "http://127.0.0.1:4001",
+            &format!("0x{}", Bytes32::new([2; 32]))
+        )
+        .is_none());
+
+        // Same network, but a different genesis block.
+        let reset_genesis = format!("0x{}", Bytes32::new([2; 32]));
+        assert!(
+            find_artifact_from_reset_network(output_dir.path(), endpoint, &reset_genesis).is_some()
+        );
+    }
+
+    #[test]
+    fn test_state_root_from_recorded_storage_slots() {
+        let output_dir = tempfile::tempdir().unwrap();
+        let mut storage_slots = vec![
+            StorageSlot::new(Bytes32::new([2; 32]), Bytes32::new([3; 32])),
+            StorageSlot::new(Bytes32::new([1; 32]), Bytes32::zeroed()),
+        ];
+        storage_slots.sort();
+        let artifact = DeploymentArtifact {
+            storage_slot_count: storage_slots.len(),
+            storage_slots: Some(storage_slots.clone()),
+            ..test_deployment_artifact()
+        };
+        let path = artifact
+            .to_file(output_dir.path(), "contract", ContractId::default())
+            .unwrap();
+
+        let recorded: DeploymentArtifact =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        let recorded_slots = recorded.storage_slots.unwrap();
+        assert_eq!(
+            Contract::initial_state_root(recorded_slots.iter()),
+            Contract::initial_state_root(storage_slots.iter())
+        );
+    }
+
+    #[test]
+    fn test_find_latest_artifact() {
+        let output_dir = tempfile::tempdir().unwrap();
+        let endpoint = crate::constants::NODE_URL;
+        for (contract_id, block_height) in [([1; 32], 10), ([2; 32], 20)] {
+            DeploymentArtifact {
+                contract_id: format!("0x{}", ContractId::new(contract_id)),
+                deployed_block_height: block_height,
+                ..test_deployment_artifact()
+            }
+            .to_file(output_dir.path(), "contract", ContractId::new(contract_id))
+            .unwrap();
+        }
+
+        let latest = find_latest_artifact(output_dir.path(), "contract", endpoint).unwrap();
+        assert_eq!(latest.deployed_block_height, 20);
+        assert!(find_latest_artifact(output_dir.path(), "other_contract", endpoint).is_none());
+        assert!(
+            find_latest_artifact(output_dir.path(), "contract", "http://127.0.0.1:4001").is_none()
+        );
+    }
+
+    #[test]
+    fn test_encrypted_deployment_artifact_roundtrip() {
+        let output_dir = tempfile::tempdir().unwrap();
+        let artifact = test_deployment_artifact();
+        let path = artifact
+            .to_encrypted_file(output_dir.path(), "contract", ContractId::default(), "pass")
+            .unwrap();
+        assert_eq!(
+            path,
+            output_dir.path().join(format!(
+                "contract-deployment-0x{}.{ENCRYPTED_ARTIFACT_EXTENSION}",
+                ContractId::default()
+            ))
+        );
+        let decrypted = DeploymentArtifact::from_encrypted_file(&path, "pass").unwrap();
+        assert_eq!(
+            serde_json::to_value(&decrypted).unwrap(),
+            serde_json::to_value(&artifact).unwrap()
+        );
+        assert!(DeploymentArtifact::from_encrypted_file(&path, "wrong").is_err());
+    }
+}
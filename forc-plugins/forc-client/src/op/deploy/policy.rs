@@ -0,0 +1,188 @@
+use super::CancellationToken;
+use crate::{
+    cmd,
+    constants::{
+        DEFAULT_NODE_TIMEOUT_MS, DEFAULT_POLL_INTERVAL_MS, DEFAULT_RETRY_BACKOFF_MS,
+        TX_SUBMIT_TIMEOUT_MS,
+    },
+};
+use anyhow::Result;
+use forc_tracing::println_warning;
+use std::{future::Future, time::Duration};
+
+/// The timing of a deployment's interactions with the node.
+///
+/// The CLI builds it from the `--*-ms` and `--retries` flags, while library users can pass one
+/// directly via [super::DeployOptions::with_policy].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeployPolicy {
+    /// The maximum time to wait for a submitted deployment to be committed.
+    pub submit_timeout: Duration,
+    /// The maximum time to wait for the node when connecting to it and querying its chain info.
+    pub connect_timeout: Duration,
+    /// The interval at which the node is polled while waiting for a deployment to be committed.
+    pub poll_interval: Duration,
+    /// The number of times a failed submission is retried.
+    pub retries: u32,
+    /// The delay before the first retry, doubled for each following one.
+    pub backoff: Duration,
+}
+
+impl Default for DeployPolicy {
+    fn default() -> Self {
+        Self {
+            submit_timeout: Duration::from_millis(TX_SUBMIT_TIMEOUT_MS),
+            connect_timeout: Duration::from_millis(DEFAULT_NODE_TIMEOUT_MS),
+            poll_interval: Duration::from_millis(DEFAULT_POLL_INTERVAL_MS),
+            retries: 0,
+            backoff: Duration::from_millis(DEFAULT_RETRY_BACKOFF_MS),
+        }
+    }
+}
+
+impl From<&cmd::Deploy> for DeployPolicy {
+    fn from(command: &cmd::Deploy) -> Self {
+        Self {
+            submit_timeout: Duration::from_millis(command.submit_timeout_ms),
+            connect_timeout: Duration::from_millis(command.node_timeout_ms),
+            poll_interval: Duration::from_millis(command.poll_interval_ms),
+            retries: command.retries,
+            backoff: Duration::from_millis(command.retry_backoff_ms),
+        }
+    }
+}
+
+impl DeployPolicy {
+    /// Returns the delay before the given retry, starting from 0.
+    fn backoff(&self, retry: u32) -> Duration {
+        self.backoff.saturating_mul(2u32.saturating_pow(retry))
+    }
+
+    /// Runs `f` until it succeeds, retrying up to `retries` times with exponential backoff.
+    ///
+    /// Only the errors for which `retryable` holds are retried, the others are returned as is.
+    /// Cancelling the given token during a backoff fails with a [super::DeployCancelled] error
+    /// rather than retrying.
+    pub(crate) async fn retry<T, Fut: Future<Output = Result<T>>>(
+        &self,
+        what: &str,
+        retryable: impl Fn(&anyhow::Error) -> bool,
+        cancellation: &CancellationToken,
+        mut f: impl FnMut() -> Fut,
+    ) -> Result<T> {
+        let mut retry = 0;
+        loop {
+            match f().await {
+                Ok(value) => return Ok(value),
+                Err(e) if retry < self.retries && retryable(&e) => {
+                    let backoff = self.backoff(retry);
+                    println_warning(&format!(
+                        "Failed to {what}, retrying in {}ms ({}/{}): {e}",
+                        backoff.as_millis(),
+                        retry + 1,
+                        self.retries
+                    ));
+                    cancellation.run(tokio::time::sleep(backoff)).await?;
+                    retry += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::op::DeployCancelled;
+
+    #[test]
+    fn test_default_policy_matches_cli_defaults() {
+        assert_eq!(
+            DeployPolicy::from(&cmd::Deploy::default()),
+            DeployPolicy::default()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry() {
+        let policy = DeployPolicy {
+            retries: 2,
+            backoff: Duration::from_millis(1),
+            ..Default::default()
+        };
+        assert_eq!(policy.backoff(0), Duration::from_millis(1));
+        assert_eq!(policy.backoff(2), Duration::from_millis(4));
+
+        // Succeeds on the last retry.
+        let mut attempts = 0;
+        let result = policy
+            .retry(
+                "succeed",
+                |_| true,
+                &CancellationToken::default(),
+                || {
+                    attempts += 1;
+                    let attempt = attempts;
+                    async move {
+                        if attempt < 3 {
+                            anyhow::bail!("attempt {attempt} failed")
+                        }
+                        Ok(attempt)
+                    }
+                },
+            )
+            .await;
+        assert_eq!(result.unwrap(), 3);
+
+        // Gives up once the retries are exhausted.
+        let mut attempts = 0;
+        let result: Result<()> = policy
+            .retry(
+                "fail",
+                |_| true,
+                &CancellationToken::default(),
+                || {
+                    attempts += 1;
+                    async { anyhow::bail!("failed") }
+                },
+            )
+            .await;
+        assert!(result.is_err());
+        assert_eq!(attempts, 3);
+
+        // Doesn't retry the errors that aren't retryable.
+        let mut attempts = 0;
+        let result: Result<()> = policy
+            .retry(
+                "fail for good",
+                |e| !e.to_string().contains("rejected"),
+                &CancellationToken::default(),
+                || {
+                    attempts += 1;
+                    async { anyhow::bail!("rejected") }
+                },
+            )
+            .await;
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+
+        // Stops retrying once cancelled.
+        let cancellation = CancellationToken::default();
+        cancellation.cancel();
+        let mut attempts = 0;
+        let result: Result<()> = policy
+            .retry(
+                "cancel",
+                |_| true,
+                &cancellation,
+                || {
+                    attempts += 1;
+                    async { anyhow::bail!("failed") }
+                },
+            )
+            .await;
+        assert!(result.unwrap_err().is::<DeployCancelled>());
+        assert_eq!(attempts, 1);
+    }
+}
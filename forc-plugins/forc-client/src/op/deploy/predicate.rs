@@ -0,0 +1,96 @@
+use super::deployments_output_dir;
+use crate::cmd;
+use anyhow::Result;
+use forc_pkg::BuiltPackage;
+use fuel_tx::{Address, Input};
+use serde::{Deserialize, Serialize};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use tracing::info;
+
+/// Records the address of a predicate built with `--include-predicates`.
+///
+/// Unlike contracts, predicates aren't deployed: their address is the root of their bytecode, so
+/// it can be funded or referenced as soon as it is known.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PredicateArtifact {
+    predicate_address: String,
+    bytecode_size: usize,
+}
+
+impl PredicateArtifact {
+    fn new(address: Address, bytecode_size: usize) -> Self {
+        Self {
+            predicate_address: format!("0x{address}"),
+            bytecode_size,
+        }
+    }
+
+    /// Writes the artifact to `output_dir`, as `<PREDICATE_NAME>-predicate-<ADDRESS>.json` to
+    /// keep it apart from the deployment artifacts of contracts.
+    fn to_file(&self, output_dir: &Path, pkg_name: &str) -> Result<PathBuf> {
+        std::fs::create_dir_all(output_dir)?;
+        let path = output_dir.join(format!(
+            "{pkg_name}-predicate-{}.json",
+            self.predicate_address
+        ));
+        let file = std::fs::File::create(&path)?;
+        serde_json::to_writer_pretty(&file, self)?;
+        Ok(path)
+    }
+}
+
+/// Computes the address of each of the given predicates, printing it and recording it in a
+/// predicate artifact.
+pub(crate) fn record_predicates(
+    command: &cmd::Deploy,
+    predicates: &[Arc<BuiltPackage>],
+) -> Result<()> {
+    for pkg in predicates {
+        let manifest = &pkg.descriptor.manifest_file;
+        let pkg_name = manifest.project_name();
+        let bytecode = &pkg.bytecode.bytes;
+        let address = Input::predicate_owner(bytecode);
+        let artifact = PredicateArtifact::new(address, bytecode.len());
+        let artifact_path = artifact
+            .to_file(&deployments_output_dir(command, manifest)?, pkg_name)?
+            .canonicalize()?;
+
+        info!("\n\nPredicate {pkg_name} address: 0x{address}");
+        info!("Predicate artifact: {}", artifact_path.display());
+        if command.json {
+            println!(
+                "{}",
+                serde_json::json!({
+                    "predicate": pkg_name,
+                    "predicate_address": artifact.predicate_address,
+                    "artifact_path": artifact_path,
+                })
+            );
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_predicate_artifact_to_file() {
+        let output_dir = tempfile::tempdir().unwrap();
+        let bytecode = vec![0u8; 8];
+        let address = Input::predicate_owner(&bytecode);
+        let artifact = PredicateArtifact::new(address, bytecode.len());
+        let path = artifact.to_file(output_dir.path(), "signer").unwrap();
+        assert_eq!(
+            path.file_name().unwrap().to_str().unwrap(),
+            format!("signer-predicate-0x{address}.json")
+        );
+        let written: PredicateArtifact =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(written, artifact);
+    }
+}
@@ -0,0 +1,124 @@
+use crate::cmd;
+use anyhow::{bail, Context, Result};
+use forc_pkg::source::{self, git};
+use std::{fs, path::Path, str::FromStr};
+use tempfile::TempDir;
+use tracing::info;
+
+/// Returns the git reference selected by `--branch`, `--git-tag` or `--rev`.
+fn git_reference(command: &cmd::Deploy) -> git::Reference {
+    if let Some(branch) = &command.branch {
+        git::Reference::Branch(branch.clone())
+    } else if let Some(tag) = &command.git_tag {
+        git::Reference::Tag(tag.clone())
+    } else if let Some(rev) = &command.rev {
+        git::Reference::Rev(rev.clone())
+    } else {
+        git::Reference::DefaultBranch
+    }
+}
+
+/// Returns the name of the repository at `repo`, eg. `counter` for
+/// `https://github.com/FuelLabs/counter.git`.
+fn repo_name(repo: &str) -> &str {
+    let name = repo
+        .trim_end_matches('/')
+        .rsplit(['/', ':'])
+        .next()
+        .unwrap_or(repo);
+    name.strip_suffix(".git").unwrap_or(name)
+}
+
+/// Fetches the package at the root of the `--git` repository and copies it to a temporary
+/// workspace to build it from, leaving the cached checkout untouched.
+///
+/// Unless `--offline` is passed, the selected reference is resolved against the remote. Offline,
+/// only a previously cached checkout of a `--rev` can be deployed.
+pub(crate) fn checkout_git_package(command: &cmd::Deploy, repo: &str) -> Result<TempDir> {
+    let name = repo_name(repo);
+    let source = git::Source {
+        repo: git::Url::from_str(repo)
+            .with_context(|| format!("Invalid `--git` repository URL: {repo}"))?,
+        reference: git_reference(command),
+    };
+    let offline = command.pkg.offline || command.frozen;
+    let fetch_id = source::fetch_id(Path::new(repo), std::time::Instant::now());
+    let pinned = if !offline {
+        git::pin(fetch_id, name, source)?
+    } else if let Some(rev) = &command.rev {
+        git::Pinned {
+            source,
+            commit_hash: rev.clone(),
+        }
+    } else {
+        bail!("Deploying a `--git` package offline requires the `--rev` of a previously fetched commit");
+    };
+
+    let checkout = git::commit_path(name, &pinned.source.repo, &pinned.commit_hash);
+    {
+        // Co-ordinate access to the checkout with the fetches of forc-pkg.
+        let mut lock = forc_util::path_lock(&checkout)?;
+        let _guard = lock.write()?;
+        if !checkout.exists() {
+            if offline {
+                bail!("No cached checkout of {pinned} found, it can't be fetched offline");
+            }
+            info!("Fetching {pinned}");
+            git::fetch(fetch_id, name, &pinned)?;
+        }
+    }
+    info!("Deploying {repo} at commit {}", pinned.commit_hash);
+
+    let workspace = tempfile::tempdir()?;
+    copy_dir(&checkout, workspace.path())?;
+    Ok(workspace)
+}
+
+/// Recursively copies the contents of `from` into the existing directory `to`.
+fn copy_dir(from: &Path, to: &Path) -> Result<()> {
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let dest = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            fs::create_dir(&dest)?;
+            copy_dir(&entry.path(), &dest)?;
+        } else {
+            fs::copy(entry.path(), dest)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repo_name() {
+        assert_eq!(
+            repo_name("https://github.com/FuelLabs/counter.git"),
+            "counter"
+        );
+        assert_eq!(repo_name("https://github.com/FuelLabs/counter/"), "counter");
+        assert_eq!(repo_name("git@github.com:counter.git"), "counter");
+    }
+
+    #[test]
+    fn test_copy_dir() {
+        let from = tempfile::tempdir().unwrap();
+        fs::create_dir(from.path().join("src")).unwrap();
+        fs::write(from.path().join("Forc.toml"), "[project]").unwrap();
+        fs::write(from.path().join("src/main.sw"), "contract;").unwrap();
+
+        let to = tempfile::tempdir().unwrap();
+        copy_dir(from.path(), to.path()).unwrap();
+        assert_eq!(
+            fs::read_to_string(to.path().join("Forc.toml")).unwrap(),
+            "[project]"
+        );
+        assert_eq!(
+            fs::read_to_string(to.path().join("src/main.sw")).unwrap(),
+            "contract;"
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use super::manifest_network_salt;
+use crate::NodeTarget;
+use anyhow::Result;
+use forc_pkg::PackageManifestFile;
+use fuel_tx::Salt;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt};
+
+/// Selects the salt each contract is deployed with.
+///
+/// `forc deploy` maps its salt flags to the built-in strategies of this module. Library users
+/// with salt policies the flags don't cover, eg. deriving salts from a tenant ID or from a secret
+/// held by a KMS, can implement this trait and deploy with it via
+/// [super::DeployOptions::with_salt_strategy].
+///
+/// The `--salt-prefix`, if any, is applied on top of the selected salt.
+pub trait SaltStrategy: Send + Sync {
+    /// Returns the salt to deploy the contract with the given manifest with.
+    fn salt(&self, manifest: &PackageManifestFile) -> Result<Salt>;
+}
+
+/// Deploys each contract with a random salt.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RandomSalt;
+
+impl SaltStrategy for RandomSalt {
+    fn salt(&self, _manifest: &PackageManifestFile) -> Result<Salt> {
+        Ok(rand::random())
+    }
+}
+
+/// Deploys each contract with the default salt, as `--default-salt` does.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DefaultSalt;
+
+impl SaltStrategy for DefaultSalt {
+    fn salt(&self, _manifest: &PackageManifestFile) -> Result<Salt> {
+        Ok(Salt::default())
+    }
+}
+
+/// Deploys each contract with the salt given for its name, as `--salt` and `--salt-file` do, and
+/// the contracts without one with the default salt.
+#[derive(Clone, Debug, Default)]
+pub struct ExplicitSalts(pub BTreeMap<String, Salt>);
+
+impl SaltStrategy for ExplicitSalts {
+    fn salt(&self, manifest: &PackageManifestFile) -> Result<Salt> {
+        Ok(self
+            .0
+            .get(manifest.project_name())
+            .copied()
+            .unwrap_or_default())
+    }
+}
+
+/// Deploys each contract with the salt configured in its manifest's `[network]` table if the
+/// deployment targets that network, or with a random salt otherwise.
+pub(crate) struct ManifestSalt<'a> {
+    pub(crate) node_target: &'a NodeTarget,
+}
+
+impl SaltStrategy for ManifestSalt<'_> {
+    fn salt(&self, manifest: &PackageManifestFile) -> Result<Salt> {
+        match manifest_network_salt(self.node_target, manifest) {
+            Some(salt) => Ok(salt),
+            None => RandomSalt.salt(manifest),
+        }
+    }
+}
+
+/// Where the salt a contract is deployed with was taken from, reported alongside the salt so that
+/// the contract ID can be traced back to the input that determined it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SaltSource {
+    /// Given via `--salt`.
+    Explicit,
+    /// Read from the `--salt-file`.
+    File,
+    /// Derived from the git commit via `--salt-from-git`.
+    Git,
+    /// The default salt, via `--default-salt` or for contracts missing from the `--salt` or
+    /// `--salt-file` map.
+    Default,
+    /// Configured in the manifest's `[network]` table.
+    Manifest,
+    /// Generated randomly.
+    Random,
+    /// Selected by the salt strategy given to [super::DeployOptions::with_salt_strategy].
+    Strategy,
+    /// Incremented by `--salt-auto-bump` from the selected salt, whose contract ID was taken.
+    Bumped,
+}
+
+impl fmt::Display for SaltSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let source = match self {
+            Self::Explicit => "explicit",
+            Self::File => "salt file",
+            Self::Git => "derived from git",
+            Self::Default => "default",
+            Self::Manifest => "manifest",
+            Self::Random => "random",
+            Self::Strategy => "salt strategy",
+            Self::Bumped => "bumped to a free contract ID",
+        };
+        write!(f, "{source}")
+    }
+}
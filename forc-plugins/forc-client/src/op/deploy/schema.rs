@@ -0,0 +1,182 @@
+use crate::constants::DEPLOYMENT_ARTIFACT_SCHEMA_VERSION;
+use serde_json::{json, Value};
+
+/// Returns the JSON Schema of the [super::DeploymentArtifact] written for each deployed contract,
+/// printed by `--print-json-schema`.
+///
+/// The schema lists the fields of the artifact as it is serialized. Optional fields are omitted
+/// from the artifacts that don't set them, and unknown fields are allowed so that older parsers
+/// keep accepting artifacts with fields added since. The schema is identified by the
+/// [DEPLOYMENT_ARTIFACT_SCHEMA_VERSION] recorded in the artifacts it describes.
+pub(crate) fn deployment_artifact_schema() -> Value {
+    let string = |description: &str| json!({ "type": "string", "description": description });
+    let integer =
+        |description: &str| json!({ "type": "integer", "minimum": 0, "description": description });
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$id": format!("urn:forc-deploy:deployment-artifact:v{DEPLOYMENT_ARTIFACT_SCHEMA_VERSION}"),
+        "title": "DeploymentArtifact",
+        "version": DEPLOYMENT_ARTIFACT_SCHEMA_VERSION,
+        "description": "The details of a contract deployment, written by `forc deploy`.",
+        "type": "object",
+        "properties": {
+            "schema_version": {
+                "type": "integer",
+                "const": DEPLOYMENT_ARTIFACT_SCHEMA_VERSION,
+                "description": "The version of the artifact format, which this schema describes.",
+            },
+            "transaction_id": string("The ID of the deployment transaction, as 0x-prefixed hex."),
+            "salt": string("The salt the contract was deployed with, as 0x-prefixed hex."),
+            "network_endpoint": string("The URL of the node the contract was deployed through."),
+            "network_name": string("The name of the network the contract was deployed to."),
+            "chain_id": integer("The ID of the chain the contract was deployed to."),
+            "contract_id": string("The ID of the deployed contract, as 0x-prefixed hex."),
+            "deployment_size": integer("The size of the deployed bytecode, in bytes."),
+            "deployed_block_height": integer("The height of the block the contract was deployed in."),
+            "created_at": integer("When the artifact was created, in seconds since the Unix epoch."),
+            "genesis_block_id": string("The ID of the genesis block of the chain, as 0x-prefixed hex."),
+            "abi": {
+                "type": "object",
+                "description": "The JSON ABI of the deployed contract.",
+            },
+            "storage_slot_count": integer("The number of storage slots the contract was deployed with."),
+            "storage_slots": {
+                "type": "array",
+                "description": "The sorted storage slots the contract was deployed with.",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "key": { "type": "string" },
+                        "value": { "type": "string" },
+                    },
+                    "required": ["key", "value"],
+                },
+            },
+            "rehearsal": {
+                "type": "object",
+                "description": "The fork a `--fork` deployment was rehearsed against.",
+                "properties": {
+                    "fork": string("The URL of the node running the fork."),
+                    "fork_block_height": integer("The block height of the fork before the deployment."),
+                },
+                "required": ["fork", "fork_block_height"],
+            },
+            "tags": {
+                "type": "object",
+                "description": "The metadata the deployment was tagged with via `--tag`.",
+                "additionalProperties": { "type": "string" },
+            },
+            "salt_source": {
+                "type": "string",
+                "description": "Where the salt was taken from.",
+                "enum": ["explicit", "file", "git", "default", "manifest", "random", "strategy", "bumped"],
+            },
+        },
+        "required": [
+            "schema_version",
+            "transaction_id",
+            "salt",
+            "network_endpoint",
+            "chain_id",
+            "contract_id",
+            "deployment_size",
+            "deployed_block_height",
+            "storage_slot_count",
+        ],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::op::deploy::{
+        salt_strategy::SaltSource, test::test_deployment_artifact, DeploymentArtifact, Rehearsal,
+    };
+    use fuel_tx::{Bytes32, StorageSlot};
+    use std::collections::BTreeSet;
+
+    fn keys(value: &Value) -> BTreeSet<String> {
+        value.as_object().unwrap().keys().cloned().collect()
+    }
+
+    #[test]
+    fn test_schema_matches_artifact() {
+        let schema = deployment_artifact_schema();
+        let artifact = DeploymentArtifact {
+            network_name: Some("testnet".to_string()),
+            created_at: Some(0),
+            genesis_block_id: Some(format!("0x{}", Bytes32::default())),
+            abi: Some(
+                serde_json::from_str(
+                    r#"{"types":[],"functions":[],"loggedTypes":[],"messagesTypes":[],"configurables":[]}"#,
+                )
+                .unwrap(),
+            ),
+            storage_slots: Some(vec![StorageSlot::new(
+                Bytes32::default(),
+                Bytes32::default(),
+            )]),
+            rehearsal: Some(Rehearsal {
+                fork: "http://127.0.0.1:4000".to_string(),
+                fork_block_height: 0,
+            }),
+            tags: [("release".to_string(), "1".to_string())].into(),
+            salt_source: Some(SaltSource::Random),
+            ..test_deployment_artifact()
+        };
+        let full = serde_json::to_value(&artifact).unwrap();
+        assert_eq!(keys(&schema["properties"]), keys(&full));
+        assert_eq!(
+            keys(&schema["properties"]["rehearsal"]["properties"]),
+            keys(&full["rehearsal"])
+        );
+
+        let minimal = serde_json::to_value(test_deployment_artifact()).unwrap();
+        let required: BTreeSet<_> = schema["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|key| key.as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(required, keys(&minimal));
+    }
+
+    #[test]
+    fn test_schema_version_matches_artifact() {
+        let schema = deployment_artifact_schema();
+        let artifact = serde_json::to_value(test_deployment_artifact()).unwrap();
+        assert_eq!(schema["version"], artifact["schema_version"]);
+        assert_eq!(
+            schema["properties"]["schema_version"]["const"],
+            artifact["schema_version"]
+        );
+
+        // Artifacts written before the version was recorded read as version 0.
+        let mut unversioned = artifact;
+        unversioned
+            .as_object_mut()
+            .unwrap()
+            .remove("schema_version");
+        let unversioned: DeploymentArtifact = serde_json::from_value(unversioned).unwrap();
+        assert_eq!(unversioned.schema_version, 0);
+    }
+
+    #[test]
+    fn test_schema_lists_salt_sources() {
+        let sources = [
+            SaltSource::Explicit,
+            SaltSource::File,
+            SaltSource::Git,
+            SaltSource::Default,
+            SaltSource::Manifest,
+            SaltSource::Random,
+            SaltSource::Strategy,
+            SaltSource::Bumped,
+        ]
+        .map(|source| serde_json::to_value(source).unwrap());
+        assert_eq!(
+            deployment_artifact_schema()["properties"]["salt_source"]["enum"],
+            json!(sources)
+        );
+    }
+}
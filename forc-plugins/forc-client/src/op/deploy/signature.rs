@@ -0,0 +1,150 @@
+use super::DeploymentArtifact;
+use anyhow::{bail, Context, Result};
+use fuel_crypto::{Message, PublicKey, SecretKey, Signature};
+use fuel_tx::{Address, Input};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+/// The file extension appended to the path of a deployment artifact to name its signature.
+const SIGNATURE_EXTENSION: &str = "sig";
+
+/// A detached signature of a deployment artifact, written next to it by `--sign-artifact`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArtifactSignature {
+    /// The address of the key that signed the artifact.
+    pub signer: Address,
+    /// The signature of the canonical JSON of the artifact.
+    pub signature: Signature,
+}
+
+impl ArtifactSignature {
+    /// Returns the path of the signature of the artifact at `artifact_path`.
+    pub fn path(artifact_path: &Path) -> PathBuf {
+        let mut path = artifact_path.as_os_str().to_owned();
+        path.push(".");
+        path.push(SIGNATURE_EXTENSION);
+        PathBuf::from(path)
+    }
+
+    /// Signs the given artifact with `secret_key`.
+    pub fn sign(artifact: &DeploymentArtifact, secret_key: &SecretKey) -> Result<Self> {
+        let message = canonical_message(serde_json::to_value(artifact)?)?;
+        Ok(Self {
+            signer: Input::owner(&PublicKey::from(secret_key)),
+            signature: Signature::sign(secret_key, &message),
+        })
+    }
+
+    /// Writes the signature of the artifact at `artifact_path` next to it, returning the path of
+    /// the written file.
+    pub fn to_file(&self, artifact_path: &Path) -> Result<PathBuf> {
+        let path = Self::path(artifact_path);
+        std::fs::write(&path, serde_json::to_string_pretty(self)?)?;
+        Ok(path)
+    }
+}
+
+/// Recursively sorts the keys of the objects within `value`, so that its serialization doesn't
+/// depend on the order the keys were written in.
+fn canonicalize(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| (key, canonicalize(value)))
+                .collect::<BTreeMap<_, _>>()
+                .into_iter()
+                .collect(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(canonicalize).collect()),
+        value => value,
+    }
+}
+
+/// Returns the message signed for the artifact with the given JSON: the hash of its canonical,
+/// compact serialization.
+fn canonical_message(artifact: Value) -> Result<Message> {
+    let canonical_json = serde_json::to_vec(&canonicalize(artifact))?;
+    Ok(Message::new(canonical_json))
+}
+
+/// Verifies the artifact at `artifact_path` against the signature written next to it by
+/// `--sign-artifact`, returning the address of the signer.
+///
+/// The signature covers the canonical JSON of the artifact, so reformatting the artifact doesn't
+/// invalidate it while changing any of its values does. Encrypted artifacts are never signed.
+pub fn verify_artifact_signature(artifact_path: &Path) -> Result<Address> {
+    let artifact = std::fs::read_to_string(artifact_path)
+        .with_context(|| format!("Failed to read deployment artifact {artifact_path:?}"))?;
+    let artifact: Value = serde_json::from_str(&artifact)
+        .with_context(|| format!("Failed to parse deployment artifact {artifact_path:?}"))?;
+    let signature_path = ArtifactSignature::path(artifact_path);
+    let signature = std::fs::read_to_string(&signature_path)
+        .with_context(|| format!("Failed to read artifact signature {signature_path:?}"))?;
+    let ArtifactSignature { signer, signature } = serde_json::from_str(&signature)
+        .with_context(|| format!("Failed to parse artifact signature {signature_path:?}"))?;
+
+    let message = canonical_message(artifact)?;
+    let recovered = signature
+        .recover(&message)
+        .map(|public_key| Input::owner(&public_key));
+    if recovered.ok() != Some(signer) {
+        bail!(
+            "The signature {signature_path:?} doesn't match the deployment artifact {artifact_path:?}: \
+            the artifact was altered or not signed by 0x{signer}"
+        );
+    }
+    Ok(signer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::op::deploy::test::test_deployment_artifact;
+    use fuel_tx::ContractId;
+
+    #[test]
+    fn test_canonicalize() {
+        let a: Value = serde_json::from_str(r#"{"b":1,"a":{"d":[{"f":2,"e":3}],"c":4}}"#).unwrap();
+        let b: Value = serde_json::from_str(r#"{"a":{"c":4,"d":[{"e":3,"f":2}]},"b":1}"#).unwrap();
+        assert_eq!(
+            serde_json::to_string(&canonicalize(a)).unwrap(),
+            serde_json::to_string(&canonicalize(b)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_verify_artifact_signature() {
+        let output_dir = tempfile::tempdir().unwrap();
+        let secret_key = SecretKey::from([1; 32]);
+        let artifact = test_deployment_artifact();
+        let artifact_path = artifact
+            .to_file(output_dir.path(), "counter", ContractId::default())
+            .unwrap();
+        let signature = ArtifactSignature::sign(&artifact, &secret_key).unwrap();
+        signature.to_file(&artifact_path).unwrap();
+        assert_eq!(
+            verify_artifact_signature(&artifact_path).unwrap(),
+            Input::owner(&PublicKey::from(&secret_key))
+        );
+
+        // Reformatting the artifact keeps the signature valid.
+        let reformatted: Value =
+            serde_json::from_str(&std::fs::read_to_string(&artifact_path).unwrap()).unwrap();
+        std::fs::write(&artifact_path, serde_json::to_string(&reformatted).unwrap()).unwrap();
+        verify_artifact_signature(&artifact_path).unwrap();
+
+        // Altering it doesn't.
+        let altered = DeploymentArtifact {
+            deployed_block_height: 1,
+            ..artifact
+        };
+        altered
+            .to_file(output_dir.path(), "counter", ContractId::default())
+            .unwrap();
+        assert!(verify_artifact_signature(&artifact_path).is_err());
+    }
+}
@@ -0,0 +1,128 @@
+use super::configurables::configurables;
+use anyhow::{bail, Result};
+use fuel_abi_types::abi::full_program::FullTypeApplication;
+use serde::Serialize;
+use sway_core::asm_generation::ProgramABI;
+use tracing::info;
+
+/// The offset of the word holding the offset of the data section within the bytecode, right after
+/// the prologue's jump over it.
+const DATA_SECTION_OFFSET_POSITION: usize = 8;
+
+/// How the bytecode of a contract splits into its sections, printed by `--size-breakdown`.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub(crate) struct SizeBreakdown {
+    /// The size of the instructions, including the prologue.
+    code: usize,
+    /// The size of the data section, configurables included.
+    data: usize,
+    /// The size of the configurables at the start of the data section, or `None` if the size of
+    /// the last one can't be told from its type.
+    configurables: Option<usize>,
+    /// The size of the whole bytecode.
+    total: usize,
+}
+
+impl SizeBreakdown {
+    /// Splits the given bytecode into its sections, locating the configurables declared in `abi`.
+    pub(crate) fn of(bytecode: &[u8], abi: &ProgramABI) -> Result<Self> {
+        let Some(offset) = bytecode
+            .get(DATA_SECTION_OFFSET_POSITION..DATA_SECTION_OFFSET_POSITION + 8)
+            .map(|word| u64::from_be_bytes(word.try_into().unwrap()) as usize)
+            .filter(|offset| *offset <= bytecode.len())
+        else {
+            bail!("Failed to locate the data section of the bytecode");
+        };
+
+        let mut configurables = configurables(abi)?;
+        configurables.sort_by_key(|configurable| configurable.offset);
+        // Each configurable spans up to the next one, padding included. The last one spans its
+        // encoded size, rounded up to a word as the data section entries are word aligned.
+        let configurables_size = match configurables.last() {
+            Some(last) => encoded_size(&last.application).map(|size| {
+                last.offset as usize + size.next_multiple_of(8) - configurables[0].offset as usize
+            }),
+            None => Some(0),
+        };
+
+        Ok(Self {
+            code: offset,
+            data: bytecode.len() - offset,
+            configurables: configurables_size,
+            total: bytecode.len(),
+        })
+    }
+
+    /// Prints the breakdown as a table.
+    pub(crate) fn print(&self, pkg_name: &str) {
+        let configurables = match self.configurables {
+            Some(size) => format!("{size:>8} bytes"),
+            None => format!("{:>8}", "unknown"),
+        };
+        info!("Size breakdown of {pkg_name}:");
+        info!("  Code             {:>8} bytes", self.code);
+        info!("  Data             {:>8} bytes", self.data);
+        info!("    Configurables  {configurables}");
+        info!("  Total            {:>8} bytes", self.total);
+    }
+}
+
+/// Returns the size of the ABI encoding of a value of the given type, if it doesn't depend on the
+/// value.
+fn encoded_size(application: &FullTypeApplication) -> Option<usize> {
+    let type_decl = &application.type_decl;
+    let type_field = type_decl.type_field.as_str();
+    match type_field {
+        "()" => Some(0),
+        "bool" | "u8" => Some(1),
+        "u16" => Some(2),
+        "u32" => Some(4),
+        "u64" => Some(8),
+        "u256" | "b256" => Some(32),
+        _ if type_field.starts_with("struct ") || type_field.starts_with('(') => {
+            type_decl.components.iter().map(encoded_size).sum()
+        }
+        _ => {
+            if let Some(len) = type_field
+                .strip_prefix("str[")
+                .and_then(|len| len.strip_suffix(']'))
+            {
+                return len.parse().ok();
+            }
+            let len = type_field
+                .strip_prefix("[_; ")?
+                .strip_suffix(']')?
+                .parse::<usize>()
+                .ok()?;
+            Some(len * encoded_size(type_decl.components.first()?)?)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_breakdown() {
+        let json_abi = r#"{"types":[{"typeId":0,"type":"u64","components":null,"typeParameters":null},
+            {"typeId":1,"type":"b256","components":null,"typeParameters":null}],
+            "functions":[],"loggedTypes":[],"messagesTypes":[],
+            "configurables":[{"name":"SUPPLY","configurableType":{"name":"","type":0,"typeArguments":null},"offset":40},
+            {"name":"OWNER","configurableType":{"name":"","type":1,"typeArguments":null},"offset":48}]}"#;
+        let abi = ProgramABI::Fuel(serde_json::from_str(json_abi).unwrap());
+        let mut bytecode = vec![0u8; 96];
+        bytecode[8..16].copy_from_slice(&40u64.to_be_bytes());
+
+        assert_eq!(
+            SizeBreakdown::of(&bytecode, &abi).unwrap(),
+            SizeBreakdown {
+                code: 40,
+                data: 56,
+                configurables: Some(40),
+                total: 96,
+            }
+        );
+        assert!(SizeBreakdown::of(&bytecode[..8], &abi).is_err());
+    }
+}
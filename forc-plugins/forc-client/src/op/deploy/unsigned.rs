@@ -0,0 +1,319 @@
+use super::{
+    artifact_timestamp, deployed_code, deployments_output_dir, parse_tags, report_maturity,
+    resolve_tip, submit_transaction, CancellationToken, ContractIdentity, DeployPolicy,
+    DeployedContract, DeploymentArtifact, NodeInfo, NodeInfoCache, SaltSource,
+};
+use crate::{
+    cmd,
+    constants::{DEPLOYMENT_ARTIFACT_SCHEMA_VERSION, GAS_PRICE_BLOCK_HORIZON},
+    util::{
+        node_url::{get_node_url, known_network_name},
+        tx::{poll_tx_status, TransactionBuilderExt, TransactionExt},
+    },
+};
+use anyhow::{bail, Context, Result};
+use forc_pkg::BuiltPackage;
+use fuel_core_client::client::{types::TransactionStatus, FuelClient};
+use fuel_crypto::Signature;
+use fuel_tx::{
+    field::Witnesses, Address, Buildable, Cacheable, ContractId, Create, Output, Salt,
+    TransactionBuilder, TransactionFee, TxId, UniqueIdentifier, Witness,
+};
+use fuel_vm::fuel_types::ChainId;
+use std::{ops::Range, path::PathBuf};
+use sway_core::asm_generation::ProgramABI;
+use tracing::info;
+
+/// A contract deployment transaction prepared without signatures, so that the signatures of
+/// several signers, eg. the members of a multisig, can be collected offline and placed at their
+/// witness indices before it is submitted.
+///
+/// Witnesses are not part of the transaction ID, so placing signatures doesn't change the ID the
+/// signers sign.
+#[derive(Debug)]
+pub struct UnsignedDeployment {
+    tx: Create,
+    chain_id: ChainId,
+    contract_id: ContractId,
+    node_url: String,
+    signature_witnesses: Range<u16>,
+    /// The name of the deployed package, which names its deployment artifact.
+    pkg_name: String,
+    /// The directory the deployment artifact is written to.
+    output_dir: PathBuf,
+    /// The deployment artifact written once the deployment is committed, which is then completed
+    /// with the block it was committed in.
+    artifact: DeploymentArtifact,
+}
+
+impl UnsignedDeployment {
+    /// Prepares the deployment of the given contract, reserving `signature_count` witness slots
+    /// for signatures.
+    ///
+    /// The contract is deployed as `forc deploy` would deploy it with the given command: with the
+    /// configurable overrides applied, the storage slots of the given `--override-storage-slots`
+    /// file if any, and the command's tip and maturity. The given salt source is recorded in the
+    /// deployment artifact.
+    ///
+    /// The deployment is funded by the coins of `fee_payer`, which reference the first reserved
+    /// slot, so the fee payer's signature must be placed there.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn prepare(
+        command: &cmd::Deploy,
+        compiled: &BuiltPackage,
+        storage_slots_file: Option<&str>,
+        salt: Salt,
+        salt_source: SaltSource,
+        fee_payer: Address,
+        signature_count: u16,
+        policy: &DeployPolicy,
+    ) -> Result<Self> {
+        if signature_count == 0 {
+            bail!("At least one signature slot is required to fund the deployment");
+        }
+        if command.encrypt_artifact || command.sign_artifact {
+            bail!("The artifacts of unsigned deployments can't be encrypted or signed, remove `--encrypt-artifact` and `--sign-artifact`");
+        }
+        if command.set_deployer_configurable.is_some() {
+            bail!("The deployer of unsigned deployments isn't known until they are signed, remove `--set-deployer-configurable`");
+        }
+        let manifest = &compiled.descriptor.manifest_file;
+        let node_url = get_node_url(&command.node, &manifest.network)?;
+        let (bytecode, storage_slots) = deployed_code(command, compiled, storage_slots_file)?;
+        let deployment_size = bytecode.len();
+        let ContractIdentity {
+            state_root,
+            contract_id,
+            ..
+        } = ContractIdentity::new(&bytecode, &storage_slots, &salt);
+
+        let client = FuelClient::new(node_url.as_str())?;
+        let NodeInfo {
+            provider,
+            consensus_parameters,
+            genesis_block_id,
+            ..
+        } = NodeInfoCache::default()
+            .get(&node_url, &client, policy)
+            .await?;
+        let chain_id = consensus_parameters.chain_id();
+        let mut tb = TransactionBuilder::create(bytecode.into(), salt, storage_slots.clone());
+        tb.add_output(Output::contract_created(contract_id, state_root));
+        if let Some(tip) = resolve_tip(command, policy).await {
+            tb.tip(tip);
+        }
+        let maturity = command.maturity.maturity;
+        report_maturity(maturity, &provider, &node_url).await?;
+        tb.maturity(maturity.into());
+
+        // Reserve the signature slots with placeholders of the size of a signature, so that the
+        // fee estimated below accounts for them.
+        let first_signature_witness: u16 = tb.witnesses().len().try_into()?;
+        for _ in 0..signature_count {
+            tb.add_witness(Witness::from(vec![0u8; Signature::LEN]));
+        }
+        tb.fund(fee_payer, provider.clone(), first_signature_witness)
+            .await
+            .map_err(|e| if e.to_string().contains("not enough coins to fit the target") {
+                anyhow::anyhow!("Deployment failed due to insufficient funds. Please be sure to have enough coins to pay for deployment transaction.")
+            } else {
+                e
+            })?;
+
+        let gas_price = provider
+            .estimate_gas_price(GAS_PRICE_BLOCK_HORIZON)
+            .await?
+            .gas_price;
+        let max_fee = TransactionFee::checked_from_tx(
+            consensus_parameters.gas_costs(),
+            consensus_parameters.fee_params(),
+            &tb.finalize_without_signature_inner(),
+            gas_price,
+        )
+        .context("Failed to compute the fee of the deployment transaction")?
+        .max_fee();
+        tb.max_fee_limit(max_fee);
+        let tx = tb.finalize_without_signature_inner();
+
+        let artifact = DeploymentArtifact {
+            schema_version: DEPLOYMENT_ARTIFACT_SCHEMA_VERSION,
+            transaction_id: format!("0x{}", tx.id(&chain_id)),
+            salt: format!("0x{salt}"),
+            network_endpoint: node_url.clone(),
+            network_name: command
+                .network_name
+                .clone()
+                .or_else(|| known_network_name(&node_url)),
+            chain_id,
+            contract_id: format!("0x{contract_id}"),
+            deployment_size,
+            deployed_block_height: 0,
+            created_at: None,
+            genesis_block_id,
+            abi: match &compiled.program_abi {
+                ProgramABI::Fuel(abi) => Some(abi.clone()),
+                _ => None,
+            },
+            storage_slot_count: storage_slots.len(),
+            storage_slots: command.record_storage_slots.then_some(storage_slots),
+            rehearsal: None,
+            tags: parse_tags(&command.tags)?,
+            salt_source: Some(salt_source),
+        };
+
+        Ok(Self {
+            tx,
+            chain_id,
+            contract_id,
+            node_url,
+            signature_witnesses: first_signature_witness..first_signature_witness + signature_count,
+            pkg_name: manifest.project_name().to_string(),
+            output_dir: deployments_output_dir(command, manifest)?,
+            artifact,
+        })
+    }
+
+    /// The ID of the transaction, which each signer signs.
+    pub fn id(&self) -> TxId {
+        self.tx.id(&self.chain_id)
+    }
+
+    /// The ID of the contract that the transaction deploys.
+    pub fn contract_id(&self) -> ContractId {
+        self.contract_id
+    }
+
+    /// The witness indices reserved for signatures.
+    pub fn signature_witnesses(&self) -> Range<u16> {
+        self.signature_witnesses.clone()
+    }
+
+    /// Places the given signature at the given witness index, which must be one of the indices
+    /// reserved for signatures.
+    pub fn add_signature(&mut self, witness_index: u16, signature: Signature) -> Result<()> {
+        if !self.signature_witnesses.contains(&witness_index) {
+            bail!(
+                "Witness index {witness_index} is not reserved for a signature, expected one of {:?}",
+                self.signature_witnesses
+            );
+        }
+        self.tx
+            .replace_witness(witness_index, Witness::from(signature.as_ref()));
+        Ok(())
+    }
+
+    /// The reserved witness indices that no signature was placed at yet.
+    pub fn missing_signatures(&self) -> Vec<u16> {
+        let placeholder = Witness::from(vec![0u8; Signature::LEN]);
+        self.signature_witnesses
+            .clone()
+            .filter(|index| self.tx.witnesses()[*index as usize] == placeholder)
+            .collect()
+    }
+
+    /// Submits the signed transaction with the timeouts and retries of the given policy, and
+    /// waits for it to be committed.
+    ///
+    /// Once committed, the deployment artifact is written like `forc deploy` writes it, and the
+    /// deployed contract is returned along with the receipts of the transaction.
+    pub async fn submit(mut self, policy: &DeployPolicy) -> Result<DeployedContract> {
+        let missing_signatures = self.missing_signatures();
+        if !missing_signatures.is_empty() {
+            bail!("Missing signatures for witness indices {missing_signatures:?}");
+        }
+        self.tx
+            .precompute(&self.chain_id)
+            .map_err(anyhow::Error::msg)?;
+
+        let client = FuelClient::new(self.node_url.as_str())?;
+        let NodeInfo { submit_timeout, .. } = NodeInfoCache::default()
+            .get(&self.node_url, &client, policy)
+            .await?;
+        let tx_id = self.id();
+        submit_transaction(
+            &client,
+            &self.tx.into(),
+            tx_id,
+            policy,
+            &CancellationToken::default(),
+        )
+        .await?;
+        let status = tokio::time::timeout(
+            submit_timeout,
+            poll_tx_status(&client, &tx_id, policy.poll_interval),
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "Timed out waiting for contract 0x{} to deploy. The transaction may have been dropped.",
+                self.contract_id
+            )
+        })??;
+        let (block_height, receipts) = match status {
+            TransactionStatus::Success {
+                block_height,
+                receipts,
+                ..
+            } => (block_height, receipts),
+            e => bail!(
+                "contract 0x{} failed to deploy due to an error: {:?}",
+                self.contract_id,
+                e
+            ),
+        };
+
+        let artifact = DeploymentArtifact {
+            deployed_block_height: *block_height,
+            created_at: Some(artifact_timestamp()?),
+            ..self.artifact
+        };
+        let artifact_path = artifact
+            .to_file(&self.output_dir, &self.pkg_name, self.contract_id)?
+            .canonicalize()?;
+        info!("Deployment artifact: {}", artifact_path.display());
+
+        Ok(DeployedContract {
+            id: self.contract_id,
+            receipts: Some(receipts),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::op::deploy::test::test_deployment_artifact;
+
+    fn test_unsigned_deployment() -> UnsignedDeployment {
+        let mut tb = TransactionBuilder::create(Witness::default(), Salt::default(), vec![]);
+        tb.add_witness(Witness::from(vec![0u8; Signature::LEN]))
+            .add_witness(Witness::from(vec![0u8; Signature::LEN]));
+        UnsignedDeployment {
+            tx: tb.finalize_without_signature_inner(),
+            chain_id: ChainId::default(),
+            contract_id: ContractId::default(),
+            node_url: crate::constants::NODE_URL.to_string(),
+            signature_witnesses: 1..3,
+            pkg_name: "contract".to_string(),
+            output_dir: PathBuf::from("out"),
+            artifact: test_deployment_artifact(),
+        }
+    }
+
+    #[test]
+    fn test_add_signature() {
+        let mut deployment = test_unsigned_deployment();
+        let tx_id = deployment.id();
+        assert_eq!(deployment.missing_signatures(), vec![1, 2]);
+
+        let signature = Signature::from_bytes([1; Signature::LEN]);
+        deployment.add_signature(2, signature).unwrap();
+        assert_eq!(deployment.missing_signatures(), vec![1]);
+        // Placing signatures doesn't change what the signers sign.
+        assert_eq!(deployment.id(), tx_id);
+
+        // The bytecode witness and indices past the reserved ones can't be signed.
+        assert!(deployment.add_signature(0, signature).is_err());
+        assert!(deployment.add_signature(3, signature).is_err());
+    }
+}
@@ -0,0 +1,47 @@
+use forc_pkg::BuiltPackage;
+use serde::Serialize;
+use std::sync::Arc;
+use sway_types::Spanned;
+
+/// A warning emitted by the compiler while building the contract(s) to deploy.
+///
+/// The warnings are still printed by the build, and are also returned in the [super::DeployReport]
+/// for tooling that gates or displays them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BuildWarning {
+    /// The name of the package the warning was emitted for.
+    pub package: String,
+    /// The description of the warning.
+    pub message: String,
+    /// The line the warning points to, starting at 1, if it points to the source.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    /// The column the warning points to, starting at 1, if it points to the source.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+}
+
+/// Collects the warnings emitted while building the given packages.
+pub(crate) fn collect_warnings(built_pkgs: &[Arc<BuiltPackage>]) -> Vec<BuildWarning> {
+    built_pkgs
+        .iter()
+        .flat_map(|pkg| package_warnings(pkg))
+        .collect()
+}
+
+/// Collects the warnings emitted while building the given package.
+pub(crate) fn package_warnings(pkg: &BuiltPackage) -> Vec<BuildWarning> {
+    pkg.warnings
+        .iter()
+        .map(|warning| {
+            let span = warning.span();
+            let line_col = (!span.is_dummy()).then(|| span.start_pos().line_col());
+            BuildWarning {
+                package: pkg.descriptor.name.clone(),
+                message: warning.to_friendly_warning_string(),
+                line: line_col.as_ref().map(|line_col| line_col.line),
+                column: line_col.as_ref().map(|line_col| line_col.col),
+            }
+        })
+        .collect()
+}
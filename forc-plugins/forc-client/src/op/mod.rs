@@ -2,6 +2,12 @@ mod deploy;
 mod run;
 mod submit;
 
-pub use deploy::{deploy, DeployedContract};
+pub use deploy::{
+    compute_contract_id, deploy, deploy_pkg, deploy_report, deploy_stream, deploy_with_options,
+    verify_artifact_signature, ArtifactSignature, BuildWarning, CancellationToken, DefaultSalt,
+    DeployCancelled, DeployOptions, DeployPolicy, DeployReport, DeployedContract,
+    DeploymentArtifact, ExplicitSalts, RandomSalt, SaltParseError, SaltSource, SaltStrategy,
+    TxBuilderHook, UnsignedDeployment,
+};
 pub use run::run;
 pub use submit::submit;
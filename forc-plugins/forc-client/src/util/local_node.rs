@@ -0,0 +1,78 @@
+use crate::constants::{LOCAL_NODE_STARTUP_POLL_MS, LOCAL_NODE_STARTUP_TIMEOUT_MS};
+use anyhow::{anyhow, bail, Result};
+use fuel_core_client::client::FuelClient;
+use std::{
+    net::TcpListener,
+    process::{Child, Command, Stdio},
+    time::{Duration, Instant},
+};
+
+/// An ephemeral, in-memory `fuel-core` node spawned by forc-client.
+///
+/// The node is killed once this is dropped.
+pub(crate) struct LocalNode {
+    child: Child,
+    url: String,
+}
+
+impl LocalNode {
+    /// Spawns a `fuel-core` node on a free local port and waits until it reports healthy.
+    ///
+    /// The node runs in debug mode, so the account behind the default signer is funded.
+    pub(crate) async fn spawn() -> Result<Self> {
+        let port = pick_unused_port()?;
+        let child = Command::new("fuel-core")
+            .arg("run")
+            .arg("--debug")
+            .arg("--db-type")
+            .arg("in-memory")
+            .arg("--port")
+            .arg(port.to_string())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| {
+                anyhow!("Failed to start `fuel-core`, please make sure it is installed and available in your PATH: {e}")
+            })?;
+        let node = Self {
+            child,
+            url: format!("http://127.0.0.1:{port}"),
+        };
+        node.wait_until_healthy().await?;
+        Ok(node)
+    }
+
+    /// The URL the spawned node is listening on.
+    pub(crate) fn url(&self) -> &str {
+        &self.url
+    }
+
+    async fn wait_until_healthy(&self) -> Result<()> {
+        let client = FuelClient::new(&self.url)?;
+        let started = Instant::now();
+        while started.elapsed() < Duration::from_millis(LOCAL_NODE_STARTUP_TIMEOUT_MS) {
+            if let Ok(true) = client.health().await {
+                return Ok(());
+            }
+            tokio::time::sleep(Duration::from_millis(LOCAL_NODE_STARTUP_POLL_MS)).await;
+        }
+        bail!(
+            "Spawned `fuel-core` node at {} did not become healthy within {}ms",
+            self.url,
+            LOCAL_NODE_STARTUP_TIMEOUT_MS
+        )
+    }
+}
+
+impl Drop for LocalNode {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Asks the OS for a free port by binding to port 0.
+fn pick_unused_port() -> Result<u16> {
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    Ok(listener.local_addr()?.port())
+}
@@ -1,5 +1,6 @@
 pub(crate) mod encode;
 pub(crate) mod gas;
+pub(crate) mod local_node;
 pub(crate) mod node_url;
 pub(crate) mod pkg;
 pub(crate) mod target;
@@ -29,6 +29,26 @@ pub fn get_node_url(
     Ok(node_url)
 }
 
+/// Returns whether the given node URL points at a node running on this machine.
+pub fn is_local_node_url(node_url: &str) -> bool {
+    let without_scheme = node_url
+        .split_once("://")
+        .map_or(node_url, |(_, rest)| rest);
+    let authority = without_scheme.split('/').next().unwrap_or_default();
+    let host = match authority.strip_prefix('[') {
+        Some(ipv6) => ipv6.split(']').next().unwrap_or_default(),
+        None => authority.split(':').next().unwrap_or_default(),
+    };
+    matches!(host, "localhost" | "127.0.0.1" | "0.0.0.0" | "::1")
+}
+
+/// Returns the name of the well-known network at the given node URL, eg. `testnet`, if any.
+pub fn known_network_name(node_url: &str) -> Option<String> {
+    let endpoint = node_url.trim_end_matches('/');
+    let endpoint = endpoint.strip_suffix("/v1/graphql").unwrap_or(endpoint);
+    Target::from_target_url(endpoint).map(|target| target.to_string())
+}
+
 #[test]
 fn test_get_node_url_testnet() {
     let input = NodeTarget {
@@ -90,6 +110,7 @@ fn test_get_node_url_beta4() {
 fn test_get_node_url_url_beta4_manifest() {
     let network = Network {
         url: "https://beta-4.fuel.network".to_string(),
+        salt: None,
     };
     let input = NodeTarget {
         target: None,
@@ -160,3 +181,36 @@ fn test_get_node_url_same_url() {
     };
     get_node_url(&input, &None).unwrap();
 }
+
+#[test]
+fn test_is_local_node_url() {
+    for url in [
+        crate::constants::NODE_URL,
+        "http://localhost:4000/v1/graphql",
+        "http://0.0.0.0:4000",
+        "http://[::1]:4000/v1/graphql",
+        "127.0.0.1:4000",
+    ] {
+        assert!(is_local_node_url(url), "{url}");
+    }
+    for url in [
+        "https://testnet.fuel.network",
+        "https://devnet.fuel.network/v1/graphql",
+        "http://localhost.example.com:4000",
+    ] {
+        assert!(!is_local_node_url(url), "{url}");
+    }
+}
+
+#[test]
+fn test_known_network_name() {
+    assert_eq!(
+        known_network_name("https://testnet.fuel.network").as_deref(),
+        Some("testnet")
+    );
+    assert_eq!(
+        known_network_name("https://devnet.fuel.network/v1/graphql").as_deref(),
+        Some("devnet")
+    );
+    assert_eq!(known_network_name("https://example.com/v1/graphql"), None);
+}
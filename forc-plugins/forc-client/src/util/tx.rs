@@ -1,17 +1,21 @@
-use std::{collections::BTreeMap, io::Write, path::Path, str::FromStr};
+use std::{collections::BTreeMap, io::Write, path::Path, str::FromStr, time::Duration};
 
 use anyhow::{Error, Result};
 use async_trait::async_trait;
 use forc_tracing::println_warning;
+use fuel_core_client::client::{types::TransactionStatus, FuelClient};
 
 use fuel_crypto::{Message, PublicKey, SecretKey, Signature};
-use fuel_tx::{field, Address, Buildable, ContractId, Input, Output, TransactionBuilder, Witness};
+use fuel_tx::{
+    field, Address, Buildable, ContractId, Input, Output, TransactionBuilder, TxId, Witness,
+};
 use fuels_accounts::{provider::Provider, wallet::Wallet, ViewOnlyAccount};
 use fuels_core::types::{
     bech32::{Bech32Address, FUEL_BECH32_HRP},
     coin_type::CoinType,
     transaction_builders::{create_coin_input, create_coin_message_input},
 };
+use tracing::info;
 
 use forc_wallet::{
     account::{derive_secret_key, new_at_index_cli},
@@ -61,7 +65,7 @@ fn ask_user_yes_no_question(question: &str) -> Result<bool> {
     Ok(ans == "y" || ans == "Y")
 }
 
-fn collect_user_accounts(
+pub(crate) fn collect_user_accounts(
     wallet_path: &Path,
     password: &str,
 ) -> Result<BTreeMap<usize, Bech32Address>> {
@@ -84,10 +88,15 @@ pub(crate) fn prompt_forc_wallet_password(wallet_path: &Path) -> Result<String>
     Ok(password)
 }
 
-pub(crate) fn check_and_create_wallet_at_default_path(wallet_path: &Path) -> Result<()> {
+/// Offers to create a new wallet if there is none at `wallet_path`, creating it without asking if
+/// `auto_confirm` is set.
+pub(crate) fn check_and_create_wallet_at_default_path(
+    wallet_path: &Path,
+    auto_confirm: bool,
+) -> Result<()> {
     if !wallet_path.exists() {
         let question = format!("Could not find a wallet at {wallet_path:?}, would you like to create a new one? [y/N]: ");
-        let accepted = ask_user_yes_no_question(&question)?;
+        let accepted = auto_confirm || ask_user_yes_no_question(&question)?;
         let new_options = New {
             force: false,
             cache_accounts: None,
@@ -120,6 +129,42 @@ pub(crate) fn secret_key_from_forc_wallet(
     Ok(secret_key)
 }
 
+/// Ensures the given HD derivation path is of the form `m/<index>[']/...`, eg. `m/44'/1179993420'/0'/0/0`.
+pub(crate) fn validate_hd_path(derivation_path: &str) -> Result<()> {
+    let mut components = derivation_path.split('/');
+    let is_valid = components.next() == Some("m")
+        && derivation_path.contains('/')
+        && components.all(|component| {
+            let index = component.strip_suffix('\'').unwrap_or(component);
+            !index.is_empty() && index.parse::<u32>().is_ok_and(|index| index < 1 << 31)
+        });
+    if !is_valid {
+        anyhow::bail!(
+            "Invalid HD derivation path `{derivation_path}` - it must be in the form m/<index>[']/..., eg. m/44'/1179993420'/0'/0/0"
+        );
+    }
+    Ok(())
+}
+
+/// Derives the secret key at the given HD derivation path from the mnemonic of the forc-wallet
+/// vault at `wallet_path`.
+pub(crate) fn secret_key_from_forc_wallet_path(
+    wallet_path: &Path,
+    derivation_path: &str,
+    password: &str,
+) -> Result<SecretKey> {
+    let phrase = eth_keystore::decrypt_key(wallet_path, password).map_err(|e| match e {
+        eth_keystore::KeystoreError::MacMismatch => {
+            anyhow::anyhow!("Failed to access forc-wallet vault. Please check your password")
+        }
+        e => e.into(),
+    })?;
+    let phrase = String::from_utf8(phrase)?;
+    SecretKey::new_from_mnemonic_phrase_with_path(&phrase, derivation_path).map_err(|e| {
+        anyhow::anyhow!("Failed to derive a key at HD derivation path `{derivation_path}`: {e}")
+    })
+}
+
 pub(crate) fn bech32_from_secret(secret_key: &SecretKey) -> Result<Bech32Address> {
     let public_key = PublicKey::from(secret_key);
     let hashed = public_key.hash();
@@ -144,7 +189,7 @@ pub(crate) fn select_manual_secret_key(
 }
 
 /// Collect and return balances of each account in the accounts map.
-async fn collect_account_balances(
+pub(crate) async fn collect_account_balances(
     accounts_map: &AccountsMap,
     provider: &Provider,
 ) -> Result<AccountBalances> {
@@ -159,17 +204,20 @@ async fn collect_account_balances(
 }
 
 // TODO: Simplify the function signature once https://github.com/FuelLabs/sway/issues/6071 is closed.
+/// Selects the key to sign with. With forc-wallet, the account to sign with is prompted for, and
+/// the signature confirmed unless `auto_confirm` is set.
 pub(crate) async fn select_secret_key(
     wallet_mode: &WalletSelectionMode,
     default_sign: bool,
     signing_key: Option<SecretKey>,
     provider: &Provider,
+    auto_confirm: bool,
 ) -> Result<Option<SecretKey>> {
     let chain_info = provider.chain_info().await?;
     let signing_key = match wallet_mode {
         WalletSelectionMode::ForcWallet(password) => {
             let wallet_path = default_wallet_path();
-            check_and_create_wallet_at_default_path(&wallet_path)?;
+            check_and_create_wallet_at_default_path(&wallet_path, auto_confirm)?;
             // TODO: This is a very simple TUI, we should consider adding a nice TUI
             // capabilities for selections and answer collection.
             let accounts = collect_user_accounts(&wallet_path, password)?;
@@ -218,8 +266,9 @@ pub(crate) async fn select_secret_key(
                 "Do you agree to sign this transaction with {}? [y/N]: ",
                 bech32
             );
-            let accepted = ask_user_yes_no_question(&question)?;
-            if !accepted {
+            if auto_confirm {
+                info!("Signing this transaction with {bech32}");
+            } else if !ask_user_yes_no_question(&question)? {
                 anyhow::bail!("User refused to sign");
             }
 
@@ -230,6 +279,22 @@ pub(crate) async fn select_secret_key(
     Ok(signing_key)
 }
 
+/// Polls the node every `poll_interval` until the given transaction is no longer pending, and
+/// returns its final status.
+pub(crate) async fn poll_tx_status(
+    client: &FuelClient,
+    tx_id: &TxId,
+    poll_interval: Duration,
+) -> Result<TransactionStatus> {
+    loop {
+        let status = client.transaction_status(tx_id).await?;
+        if !matches!(status, TransactionStatus::Submitted { .. }) {
+            return Ok(status);
+        }
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
 #[async_trait]
 pub trait TransactionBuilderExt<Tx> {
     fn add_contract(&mut self, contract_id: ContractId) -> &mut Self;
@@ -323,7 +388,7 @@ impl<Tx: Buildable + field::Witnesses + Send> TransactionBuilderExt<Tx> for Tran
         let chain_info = provider.chain_info().await?;
         let params = chain_info.consensus_parameters;
         let signing_key =
-            select_secret_key(wallet_mode, default_sign, signing_key, &provider).await?;
+            select_secret_key(wallet_mode, default_sign, signing_key, &provider, false).await?;
         // Get the address
         let address = if let Some(key) = signing_key {
             Address::from(*key.public_key().hash())
@@ -376,3 +441,30 @@ impl<T: field::Witnesses> TransactionExt for T {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_hd_path() {
+        for valid in [
+            "m/44'/1179993420'/0'/0/0",
+            "m/44'/1179993420'/7'/0/3",
+            "m/0",
+        ] {
+            assert!(validate_hd_path(valid).is_ok(), "{valid}");
+        }
+        for invalid in [
+            "",
+            "m",
+            "m/",
+            "44'/1179993420'/0'/0/0",
+            "m/44'/x/0",
+            "m/44''/0",
+            "m/2147483648",
+        ] {
+            assert!(validate_hd_path(invalid).is_err(), "{invalid}");
+        }
+    }
+}
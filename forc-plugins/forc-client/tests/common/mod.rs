@@ -0,0 +1,160 @@
+//! Utilities shared by the integration tests, to deploy test projects against a local node.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{Child, Command},
+};
+
+use forc_client::NodeTarget;
+use fuel_tx::ContractId;
+use portpicker::Port;
+use tempfile::{tempdir, TempDir};
+use toml_edit::{Document, InlineTable, Item, Value};
+
+fn get_workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("../")
+        .join("../")
+        .canonicalize()
+        .unwrap()
+}
+
+fn test_data_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test")
+        .join("data")
+        .canonicalize()
+        .unwrap()
+}
+
+/// Copy a directory recursively from `source` to `dest`.
+fn copy_dir(source: &Path, dest: &Path) -> anyhow::Result<()> {
+    fs::create_dir_all(dest)?;
+    for e in fs::read_dir(source)? {
+        let entry = e?;
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            copy_dir(&entry.path(), &dest.join(entry.file_name()))?;
+        } else {
+            fs::copy(entry.path(), dest.join(entry.file_name()))?;
+        }
+    }
+    Ok(())
+}
+
+fn patch_manifest_file_with_path_std(manifest_dir: &Path) -> anyhow::Result<()> {
+    let toml_path = manifest_dir.join(sway_utils::constants::MANIFEST_FILE_NAME);
+    let toml_content = fs::read_to_string(&toml_path).unwrap();
+
+    let mut doc = toml_content.parse::<Document>().unwrap();
+    let new_std_path = get_workspace_root().join("sway-lib-std");
+
+    let mut std_dependency = InlineTable::new();
+    std_dependency.insert("path", Value::from(new_std_path.display().to_string()));
+    doc["dependencies"]["std"] = Item::Value(Value::InlineTable(std_dependency));
+
+    fs::write(&toml_path, doc.to_string()).unwrap();
+    Ok(())
+}
+
+/// An in-memory `fuel-core` node that is killed once dropped.
+pub struct TestNode {
+    child: Child,
+    port: Port,
+}
+
+impl TestNode {
+    /// Starts a `fuel-core` node in debug mode on a free port.
+    pub fn start() -> Self {
+        let port = portpicker::pick_unused_port().expect("No ports free");
+        let child = Command::new("fuel-core")
+            .arg("run")
+            .arg("--debug")
+            .arg("--db-type")
+            .arg("in-memory")
+            .arg("--port")
+            .arg(port.to_string())
+            .spawn()
+            .expect("Failed to start fuel-core");
+        Self { child, port }
+    }
+
+    /// The URL of the node's GraphQL endpoint.
+    pub fn url(&self) -> String {
+        format!("http://127.0.0.1:{}/v1/graphql", self.port)
+    }
+
+    /// A node target pointing at this node.
+    pub fn node_target(&self) -> NodeTarget {
+        NodeTarget {
+            node_url: Some(self.url()),
+            target: None,
+            testnet: false,
+        }
+    }
+}
+
+impl Drop for TestNode {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Copies the given project from the test data into a temporary directory, pointing its `std`
+/// dependency at the `sway-lib-std` of this repository.
+pub fn setup_test_project(name: &str) -> TempDir {
+    let tmp_dir = tempdir().unwrap();
+    copy_dir(&test_data_path().join(name), tmp_dir.path()).unwrap();
+    patch_manifest_file_with_path_std(tmp_dir.path()).unwrap();
+    tmp_dir
+}
+
+/// Copies the given projects from the test data into a temporary workspace with them as members,
+/// pointing their `std` dependency at the `sway-lib-std` of this repository.
+pub fn setup_test_workspace(members: &[&str]) -> TempDir {
+    let tmp_dir = tempdir().unwrap();
+    for member in members {
+        let member_dir = tmp_dir.path().join(member);
+        copy_dir(&test_data_path().join(member), &member_dir).unwrap();
+        patch_manifest_file_with_path_std(&member_dir).unwrap();
+    }
+    let members = members
+        .iter()
+        .map(|member| format!("\"{member}\""))
+        .collect::<Vec<_>>()
+        .join(", ");
+    fs::write(
+        tmp_dir
+            .path()
+            .join(sway_utils::constants::MANIFEST_FILE_NAME),
+        format!("[workspace]\nmembers = [{members}]\n"),
+    )
+    .unwrap();
+    tmp_dir
+}
+
+/// The path of the deployment artifact written for the given contract of the package at
+/// `pkg_dir`.
+pub fn deployment_artifact_path(
+    pkg_dir: &Path,
+    pkg_name: &str,
+    contract_id: ContractId,
+) -> PathBuf {
+    pkg_dir
+        .join("out")
+        .join("deployments")
+        .join(format!("{pkg_name}-deployment-0x{contract_id}.json"))
+}
+
+/// Reads the deployment artifact written for the given contract of the package at `pkg_dir`.
+pub fn read_deployment_artifact(
+    pkg_dir: &Path,
+    pkg_name: &str,
+    contract_id: ContractId,
+) -> serde_json::Value {
+    let artifact_path = deployment_artifact_path(pkg_dir, pkg_name, contract_id);
+    let artifact = fs::read_to_string(artifact_path).expect("missing deployment artifact");
+    serde_json::from_str(&artifact).unwrap()
+}
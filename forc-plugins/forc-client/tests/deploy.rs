@@ -1,116 +1,361 @@
+mod common;
+
 use std::{
     fs,
-    path::{Path, PathBuf},
-    process::{Child, Command},
     str::FromStr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
 };
 
+use common::{
+    deployment_artifact_path, read_deployment_artifact, setup_test_project, setup_test_workspace,
+    TestNode,
+};
 use forc::cli::shared::Pkg;
 use forc_client::{
     cmd,
-    op::{deploy, DeployedContract},
-    NodeTarget,
+    op::{
+        deploy, deploy_stream, deploy_with_options, DeployOptions, DeployPolicy, DeployedContract,
+        SaltStrategy,
+    },
 };
 use fuel_tx::{ContractId, Salt};
-use portpicker::Port;
-use tempfile::tempdir;
-use toml_edit::{Document, InlineTable, Item, Value};
-
-fn get_workspace_root() -> PathBuf {
-    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-        .join("../")
-        .join("../")
-        .canonicalize()
-        .unwrap()
+use fuels_core::types::transaction_builders::TransactionBuilder;
+use futures::StreamExt;
+
+#[tokio::test]
+async fn simple_deploy() {
+    let node = TestNode::start();
+    let project_dir = setup_test_project("standalone_contract");
+
+    let cmd = cmd::Deploy {
+        pkg: Pkg {
+            path: Some(project_dir.path().display().to_string()),
+            ..Default::default()
+        },
+        salt: Some(vec![format!("{}", Salt::default())]),
+        node: node.node_target(),
+        default_signer: true,
+        ..Default::default()
+    };
+    let contract_ids = deploy(cmd).await.unwrap();
+    let expected = vec![DeployedContract {
+        id: ContractId::from_str(
+            "822c8d3672471f64f14f326447793c7377b6e430122db23b622880ccbd8a33ef",
+        )
+        .unwrap(),
+        receipts: None,
+    }];
+
+    assert_eq!(contract_ids, expected)
+}
+
+#[tokio::test]
+async fn deploy_stream_yields_each_contract() {
+    let node = TestNode::start();
+    let project_dir = setup_test_project("standalone_contract");
+
+    let cmd = cmd::Deploy {
+        pkg: Pkg {
+            path: Some(project_dir.path().display().to_string()),
+            ..Default::default()
+        },
+        salt: Some(vec![format!("{}", Salt::default())]),
+        node: node.node_target(),
+        default_signer: true,
+        ..Default::default()
+    };
+    let stream = deploy_stream(cmd, DeployOptions::default()).await.unwrap();
+    futures::pin_mut!(stream);
+    let deployed = stream.next().await.unwrap().unwrap();
+    assert!(stream.next().await.is_none());
+
+    let expected = DeployedContract {
+        id: ContractId::from_str(
+            "822c8d3672471f64f14f326447793c7377b6e430122db23b622880ccbd8a33ef",
+        )
+        .unwrap(),
+        receipts: None,
+    };
+    assert_eq!(deployed, expected)
 }
 
-fn test_data_path() -> PathBuf {
-    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-        .join("test")
-        .join("data")
-        .canonicalize()
-        .unwrap()
+#[tokio::test]
+async fn deploy_workspace_in_deployment_order() {
+    let node = TestNode::start();
+    let members = ["standalone_contract", "standalone_contract_b"];
+    let workspace_dir = setup_test_workspace(&members);
+
+    let cmd = cmd::Deploy {
+        pkg: Pkg {
+            path: Some(workspace_dir.path().display().to_string()),
+            ..Default::default()
+        },
+        node: node.node_target(),
+        default_signer: true,
+        ..Default::default()
+    };
+    let contract_ids = deploy(cmd).await.unwrap();
+    assert_eq!(contract_ids.len(), members.len());
+
+    // Each deployment is committed before the next one is submitted, so the returned order must
+    // match the order of the block heights the contracts were deployed in.
+    let block_heights: Vec<_> = contract_ids
+        .iter()
+        .map(|contract| {
+            let member = members
+                .iter()
+                .find(|member| {
+                    deployment_artifact_path(
+                        &workspace_dir.path().join(member),
+                        member,
+                        contract.id,
+                    )
+                    .exists()
+                })
+                .expect("missing deployment artifact");
+            let artifact =
+                read_deployment_artifact(&workspace_dir.path().join(member), member, contract.id);
+            artifact["deployed_block_height"].as_u64().unwrap()
+        })
+        .collect();
+    assert!(block_heights.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[tokio::test]
+async fn deploy_writes_deployment_artifact() {
+    let node = TestNode::start();
+    let project_dir = setup_test_project("standalone_contract");
+
+    let cmd = cmd::Deploy {
+        pkg: Pkg {
+            path: Some(project_dir.path().display().to_string()),
+            ..Default::default()
+        },
+        default_salt: true,
+        node: node.node_target(),
+        default_signer: true,
+        ..Default::default()
+    };
+    let contract_ids = deploy(cmd).await.unwrap();
+    let expected_id =
+        ContractId::from_str("822c8d3672471f64f14f326447793c7377b6e430122db23b622880ccbd8a33ef")
+            .unwrap();
+    assert_eq!(
+        contract_ids,
+        vec![DeployedContract {
+            id: expected_id,
+            receipts: None,
+        }]
+    );
+
+    let artifact = read_deployment_artifact(project_dir.path(), "standalone_contract", expected_id);
+    assert_eq!(artifact["contract_id"], format!("0x{expected_id}"));
+    assert_eq!(artifact["salt"], format!("0x{}", Salt::default()));
+    assert_eq!(artifact["network_endpoint"], node.url());
+    assert!(artifact["deployment_size"].as_u64().unwrap() > 0);
 }
 
-fn run_node() -> (Child, Port) {
-    let port = portpicker::pick_unused_port().expect("No ports free");
-
-    let child = Command::new("fuel-core")
-        .arg("run")
-        .arg("--debug")
-        .arg("--db-type")
-        .arg("in-memory")
-        .arg("--port")
-        .arg(port.to_string())
-        .spawn()
-        .expect("Failed to start fuel-core");
-    (child, port)
+#[tokio::test]
+async fn deploy_with_storage_slot_override() {
+    let node = TestNode::start();
+    let project_dir = setup_test_project("standalone_contract");
+    let override_path = project_dir.path().join("storage_slots_override.json");
+    fs::write(
+        &override_path,
+        r#"[{"key":"0000000000000000000000000000000000000000000000000000000000000001","value":"0000000000000000000000000000000000000000000000000000000000000002"}]"#,
+    )
+    .unwrap();
+
+    let cmd = cmd::Deploy {
+        pkg: Pkg {
+            path: Some(project_dir.path().display().to_string()),
+            ..Default::default()
+        },
+        default_salt: true,
+        node: node.node_target(),
+        default_signer: true,
+        override_storage_slots: vec![override_path.display().to_string()],
+        ..Default::default()
+    };
+    let contract_ids = deploy(cmd).await.unwrap();
+    assert_eq!(contract_ids.len(), 1);
+
+    // The overridden storage changes the state root, and thus the contract ID.
+    let default_id =
+        ContractId::from_str("822c8d3672471f64f14f326447793c7377b6e430122db23b622880ccbd8a33ef")
+            .unwrap();
+    assert_ne!(contract_ids[0].id, default_id);
+    read_deployment_artifact(
+        project_dir.path(),
+        "standalone_contract",
+        contract_ids[0].id,
+    );
 }
 
-/// Copy a directory recursively from `source` to `dest`.
-fn copy_dir(source: &Path, dest: &Path) -> anyhow::Result<()> {
-    fs::create_dir_all(dest)?;
-    for e in fs::read_dir(source)? {
-        let entry = e?;
-        let file_type = entry.file_type()?;
-        if file_type.is_dir() {
-            copy_dir(&entry.path(), &dest.join(entry.file_name()))?;
-        } else {
-            fs::copy(entry.path(), dest.join(entry.file_name()))?;
-        }
+#[tokio::test]
+async fn deploy_with_multiple_storage_slot_overrides() {
+    let node = TestNode::start();
+    let project_dir = setup_test_project("standalone_contract");
+    let override_paths: Vec<_> = ["1", "2"]
+        .iter()
+        .map(|value| {
+            let path = project_dir
+                .path()
+                .join(format!("storage_slots_override_{value}.json"));
+            fs::write(
+                &path,
+                format!(r#"[{{"key":"0000000000000000000000000000000000000000000000000000000000000001","value":"000000000000000000000000000000000000000000000000000000000000000{value}"}}]"#),
+            )
+            .unwrap();
+            path.display().to_string()
+        })
+        .collect();
+
+    let cmd = cmd::Deploy {
+        pkg: Pkg {
+            path: Some(project_dir.path().display().to_string()),
+            ..Default::default()
+        },
+        default_salt: true,
+        node: node.node_target(),
+        default_signer: true,
+        override_storage_slots: override_paths,
+        ..Default::default()
+    };
+    let contracts = deploy(cmd).await.unwrap();
+
+    // Each storage configuration yields its own instance, with its own artifact.
+    assert_eq!(contracts.len(), 2);
+    assert_ne!(contracts[0].id, contracts[1].id);
+    for contract in &contracts {
+        read_deployment_artifact(project_dir.path(), "standalone_contract", contract.id);
     }
-    Ok(())
 }
 
-fn patch_manifest_file_with_path_std(manifest_dir: &Path) -> anyhow::Result<()> {
-    let toml_path = manifest_dir.join(sway_utils::constants::MANIFEST_FILE_NAME);
-    let toml_content = fs::read_to_string(&toml_path).unwrap();
+#[tokio::test]
+async fn deploy_captures_receipts() {
+    let node = TestNode::start();
+    let project_dir = setup_test_project("standalone_contract");
+
+    let cmd = cmd::Deploy {
+        pkg: Pkg {
+            path: Some(project_dir.path().display().to_string()),
+            ..Default::default()
+        },
+        default_salt: true,
+        node: node.node_target(),
+        default_signer: true,
+        receipts: true,
+        ..Default::default()
+    };
+    let contracts = deploy(cmd).await.unwrap();
+    assert_eq!(contracts.len(), 1);
+    assert!(contracts[0].receipts.is_some());
+}
+
+#[tokio::test]
+async fn deploy_fails_on_existing_contract() {
+    let node = TestNode::start();
+    let project_dir = setup_test_project("standalone_contract");
+    let deploy_cmd = |fail_on_existing| cmd::Deploy {
+        pkg: Pkg {
+            path: Some(project_dir.path().display().to_string()),
+            ..Default::default()
+        },
+        default_salt: true,
+        node: node.node_target(),
+        default_signer: true,
+        fail_on_existing,
+        ..Default::default()
+    };
+    deploy(deploy_cmd(true)).await.unwrap();
 
-    let mut doc = toml_content.parse::<Document>().unwrap();
-    let new_std_path = get_workspace_root().join("sway-lib-std");
+    let err = deploy(deploy_cmd(true)).await.unwrap_err();
+    assert!(err.to_string().contains("already exists"), "{err}");
+}
 
-    let mut std_dependency = InlineTable::new();
-    std_dependency.insert("path", Value::from(new_std_path.display().to_string()));
-    doc["dependencies"]["std"] = Item::Value(Value::InlineTable(std_dependency));
+/// An example salt strategy deriving the salt of each contract from the ID of the tenant it is
+/// deployed for.
+struct TenantSalt {
+    tenant_id: u8,
+}
 
-    fs::write(&toml_path, doc.to_string()).unwrap();
-    Ok(())
+impl SaltStrategy for TenantSalt {
+    fn salt(&self, _manifest: &forc_pkg::PackageManifestFile) -> anyhow::Result<Salt> {
+        Ok(Salt::new([self.tenant_id; 32]))
+    }
 }
 
 #[tokio::test]
-async fn simple_deploy() {
-    let (mut node, port) = run_node();
-    let tmp_dir = tempdir().unwrap();
-    let project_dir = test_data_path().join("standalone_contract");
-    copy_dir(&project_dir, tmp_dir.path()).unwrap();
-    patch_manifest_file_with_path_std(tmp_dir.path()).unwrap();
-
-    let pkg = Pkg {
-        path: Some(tmp_dir.path().display().to_string()),
+async fn deploy_with_custom_salt_strategy() {
+    let node = TestNode::start();
+    let project_dir = setup_test_project("standalone_contract");
+    let deploy_cmd = || cmd::Deploy {
+        pkg: Pkg {
+            path: Some(project_dir.path().display().to_string()),
+            ..Default::default()
+        },
+        node: node.node_target(),
+        default_signer: true,
         ..Default::default()
     };
 
-    let node_url = format!("http://127.0.0.1:{}/v1/graphql", port);
-    let target = NodeTarget {
-        node_url: Some(node_url),
-        target: None,
-        testnet: false,
+    // Tenant 0 derives the default salt.
+    let options = || DeployOptions::default().with_salt_strategy(TenantSalt { tenant_id: 0 });
+    let contracts = deploy_with_options(deploy_cmd(), options()).await.unwrap();
+    let default_id =
+        ContractId::from_str("822c8d3672471f64f14f326447793c7377b6e430122db23b622880ccbd8a33ef")
+            .unwrap();
+    assert_eq!(contracts[0].id, default_id);
+
+    let contracts = deploy_with_options(
+        deploy_cmd(),
+        DeployOptions::default().with_salt_strategy(TenantSalt { tenant_id: 1 }),
+    )
+    .await
+    .unwrap();
+    assert_ne!(contracts[0].id, default_id);
+
+    // The salt flags can't be combined with a salt strategy.
+    let cmd = cmd::Deploy {
+        default_salt: true,
+        ..deploy_cmd()
     };
+    assert!(deploy_with_options(cmd, options()).await.is_err());
+}
+
+#[tokio::test]
+async fn deploy_with_tx_builder_hook_customizes_transaction() {
+    let node = TestNode::start();
+    let project_dir = setup_test_project("standalone_contract");
     let cmd = cmd::Deploy {
-        pkg,
-        salt: Some(vec![format!("{}", Salt::default())]),
-        node: target,
+        pkg: Pkg {
+            path: Some(project_dir.path().display().to_string()),
+            ..Default::default()
+        },
+        default_salt: true,
+        node: node.node_target(),
         default_signer: true,
         ..Default::default()
     };
-    let contract_ids = deploy(cmd).await.unwrap();
-    node.kill().unwrap();
-    let expected = vec![DeployedContract {
-        id: ContractId::from_str(
-            "822c8d3672471f64f14f326447793c7377b6e430122db23b622880ccbd8a33ef",
-        )
-        .unwrap(),
-    }];
 
-    assert_eq!(contract_ids, expected)
+    let witnesses = Arc::new(AtomicUsize::new(0));
+    let hook_witnesses = witnesses.clone();
+    // The hook combines with a custom policy.
+    let options = DeployOptions::default()
+        .with_policy(DeployPolicy {
+            retries: 1,
+            ..Default::default()
+        })
+        .with_tx_builder_hook(move |tb| {
+            hook_witnesses.store(tb.witnesses().len(), Ordering::SeqCst);
+            Ok(())
+        });
+    let contracts = deploy_with_options(cmd, options).await.unwrap();
+    assert_eq!(contracts.len(), 1);
+    // The hook receives the prepared deployment, which holds the bytecode as a witness.
+    assert!(witnesses.load(Ordering::SeqCst) > 0);
 }